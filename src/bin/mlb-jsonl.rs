@@ -0,0 +1,430 @@
+//! `mlb-jsonl`: validates, inspects and converts game files in the format described in FORMAT.md.
+
+use mlb_jsonl_parser::{
+    Base, Context, Game, GameReader, ParseError, Parser, Play, RegexDialect, RegexStyle, bases_after_plays,
+    import_retrosheet,
+};
+use std::{collections::HashMap, env, fs, io::BufReader, process::ExitCode};
+
+const USAGE: &str = "\
+usage:
+    mlb-jsonl validate <file>...
+        checks every line of the files, printing each problem with its line number
+    mlb-jsonl inspect <file>...
+        prints the teams, score, play counts and final state of each game
+    mlb-jsonl regex <file> [--lines <count>] [--style <style>] [--dialect <dialect>]
+        prints the regex for the line following the first <count> lines of the file (all of them by default),
+        blank lines included
+    mlb-jsonl convert <file> [--from <jsonl|live-feed|retrosheet>] --to <jsonl|json|retrosheet>
+            [--output <file>]
+        writes the games of the file in another format, to standard output by default. a live feed is a game saved
+        from the MLB Stats API's feed/live endpoint, and a retrosheet file is a Retrosheet event file
+
+exits with status 1 if a file is invalid, and 2 if the arguments are.";
+
+/// A reason to stop before completing a command.
+enum Failure {
+    /// The arguments are invalid.
+    Usage(String),
+    /// A file is invalid or could not be read or written.
+    Invalid(String),
+}
+
+/// The positional arguments and `--name value` options of a command.
+struct Arguments {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Arguments {
+    /// Splits `args` into positional arguments and the options named in `allowed`.
+    fn parse(args: &[String], allowed: &[&str]) -> Result<Self, Failure> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if allowed.contains(&name) => {
+                    let value = args.next().ok_or_else(|| Failure::Usage(format!("--{name} needs a value")))?;
+                    options.insert(name.to_string(), value.clone());
+                }
+                Some(name) => return Err(Failure::Usage(format!("unknown option --{name}"))),
+                None => positional.push(arg.clone()),
+            }
+        }
+
+        Ok(Self { positional, options })
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// The single file argument of a command.
+    fn file(&self) -> Result<&str, Failure> {
+        match self.positional.as_slice() {
+            [file] => Ok(file),
+            _ => Err(Failure::Usage("expected exactly one file".to_string())),
+        }
+    }
+
+    /// The file arguments of a command taking at least one.
+    fn files(&self) -> Result<&[String], Failure> {
+        if self.positional.is_empty() {
+            return Err(Failure::Usage("expected at least one file".to_string()));
+        }
+
+        Ok(&self.positional)
+    }
+}
+
+fn read(path: &str) -> Result<String, Failure> {
+    fs::read_to_string(path).map_err(|e| Failure::Invalid(format!("{path}: could not read: {e}")))
+}
+
+/// Whether `line` is a game's context line.
+fn is_context_line(line: &str) -> bool {
+    serde_json::from_str::<Context>(line).is_ok()
+}
+
+/// Every problem with the games in `text`: the lines the generated regexes would reject, the lines that cannot be
+/// parsed, and the games that cannot be built. After a line that cannot be parsed, the rest of its game is skipped.
+fn validate_text(text: &str) -> Vec<ParseError> {
+    let mut parser = Parser::new(false);
+    let mut problems = Vec::new();
+    let mut game_started = false;
+    let mut skipping = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = Some(i + 1);
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if is_context_line(line) {
+            if game_started && !skipping {
+                problems.extend(parser.finish().err());
+            }
+            parser.reset();
+            skipping = false;
+        } else if skipping {
+            continue;
+        }
+
+        let violations = parser.explain(line);
+        problems.extend(violations.iter().map(|violation| ParseError { line_number, message: violation.to_string() }));
+        if let Err(e) = parser.advance(line) {
+            if violations.is_empty() {
+                problems.push(ParseError { line_number, ..e });
+            }
+            skipping = true;
+        }
+        game_started = true;
+    }
+
+    if !skipping {
+        problems.extend(parser.finish().err());
+    }
+
+    problems
+}
+
+fn validate(args: &[String]) -> Result<(), Failure> {
+    let arguments = Arguments::parse(args, &[])?;
+    let mut invalid_files = 0;
+
+    for path in arguments.files()? {
+        let problems = match read(path) {
+            Ok(text) => validate_text(&text),
+            Err(Failure::Invalid(message)) | Err(Failure::Usage(message)) => {
+                eprintln!("{message}");
+                invalid_files += 1;
+                continue;
+            }
+        };
+
+        if problems.is_empty() {
+            println!("{path}: ok");
+        } else {
+            invalid_files += 1;
+        }
+        for problem in problems {
+            match problem.line_number {
+                Some(line_number) => println!("{path}:{line_number}: {}", problem.message),
+                None => println!("{path}: {}", problem.message),
+            }
+        }
+    }
+
+    if invalid_files > 0 {
+        return Err(Failure::Invalid(format!("{invalid_files} invalid file(s)")));
+    }
+
+    Ok(())
+}
+
+/// The runs scored by the away and home teams.
+fn score(game: &Game) -> (usize, usize) {
+    let mut runs = (0, 0);
+    for play in &game.plays {
+        let scored = play.movements().unwrap_or_default().iter()
+            .filter(|movement| movement.end_base == Base::Home && !movement.is_out)
+            .count();
+        if play.inning().top {
+            runs.0 += scored;
+        } else {
+            runs.1 += scored;
+        }
+    }
+
+    runs
+}
+
+/// The plays of the last half inning of the game.
+fn last_half_inning(game: &Game) -> &[Play] {
+    let Some(last_play) = game.plays.last() else {
+        return &[];
+    };
+    let start = game.plays.iter().rposition(|play| play.inning() != last_play.inning()).map_or(0, |i| i + 1);
+
+    &game.plays[start..]
+}
+
+/// The inning, outs and runners on base after the last play of the game.
+fn final_state(game: &Game) -> String {
+    let plays = last_half_inning(game);
+    let Some(last_play) = plays.last() else {
+        return "no plays".to_string();
+    };
+
+    let outs = plays.iter().flat_map(|play| play.movements().unwrap_or_default()).filter(|movement| movement.is_out).count();
+    let runners = [Base::First, Base::Second, Base::Third].into_iter().zip(bases_after_plays(plays)).filter_map(|(base, runner)| {
+        runner.map(|runner| format!("{runner} on {base}"))
+    }).collect::<Vec<_>>();
+
+    format!(
+        "{} of inning {}, {outs} out(s), {}",
+        if last_play.inning().top { "top" } else { "bottom" },
+        last_play.inning().number,
+        if runners.is_empty() { "bases empty".to_string() } else { runners.join(", ") },
+    )
+}
+
+fn describe(game: &Game) -> String {
+    let context = &game.context;
+    let (away_runs, home_runs) = score(game);
+
+    let mut play_counts = HashMap::<&str, usize>::new();
+    for play in &game.plays {
+        *play_counts.entry(play.name()).or_default() += 1;
+    }
+    let mut play_counts = play_counts.into_iter().collect::<Vec<_>>();
+    play_counts.sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then(a_name.cmp(b_name)));
+
+    [
+        format!("game {} on {} at {}", context.game_pk, context.date, context.venue_name),
+        format!(
+            "  away team {} ({} players), home team {} ({} players)",
+            context.away_team.id, context.away_team.players.len(), context.home_team.id, context.home_team.players.len(),
+        ),
+        format!("  score: away {away_runs}, home {home_runs}"),
+        format!(
+            "  plays: {} ({})",
+            game.plays.len(),
+            play_counts.iter().map(|(name, count)| format!("{name} {count}")).collect::<Vec<_>>().join(", "),
+        ),
+        format!("  final state: {}", final_state(game)),
+    ].join("\n")
+}
+
+fn inspect(args: &[String]) -> Result<(), Failure> {
+    let arguments = Arguments::parse(args, &[])?;
+    let mut invalid = false;
+
+    for path in arguments.files()? {
+        let file = match fs::File::open(path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("{path}: could not read: {e}");
+                invalid = true;
+                continue;
+            }
+        };
+        for game in GameReader::new(BufReader::new(file)) {
+            match game {
+                Ok(game) => println!("{path}: {}", describe(&game)),
+                Err(e) => {
+                    eprintln!("{path}: {e}");
+                    invalid = true;
+                }
+            }
+        }
+    }
+
+    if invalid {
+        return Err(Failure::Invalid("some files or games could not be read".to_string()));
+    }
+
+    Ok(())
+}
+
+fn regex(args: &[String]) -> Result<(), Failure> {
+    let arguments = Arguments::parse(args, &["lines", "style", "dialect"])?;
+    let path = arguments.file()?;
+    let line_count = arguments.option("lines")
+        .map(|lines| lines.parse::<usize>().map_err(|_| Failure::Usage(format!("--lines expects a number, found {lines:?}"))))
+        .transpose()?;
+
+    let mut parser = Parser::new(false);
+    if let Some(style) = arguments.option("style") {
+        parser.set_regex_style(RegexStyle::from_name(style, 3).map_err(Failure::Usage)?);
+    }
+    if let Some(dialect) = arguments.option("dialect") {
+        parser.set_regex_dialect(Some(RegexDialect::from_name(dialect).map_err(Failure::Usage)?));
+    }
+
+    let text = read(path)?;
+    advance_lines(&mut parser, &text, line_count).map_err(|e| Failure::Invalid(format!("{path}: {e}")))?;
+    println!("{}", parser.generate_regex());
+
+    Ok(())
+}
+
+/// Parses the first `count` lines of `text`, or all of them, skipping blank lines but counting them, as `validate`
+/// does.
+fn advance_lines(parser: &mut Parser, text: &str, count: Option<usize>) -> Result<(), ParseError> {
+    let lines = text.lines().enumerate().take(count.unwrap_or(usize::MAX));
+    for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        parser.advance(line).map_err(|e| ParseError { line_number: Some(i + 1), ..e })?;
+    }
+
+    Ok(())
+}
+
+fn convert(args: &[String]) -> Result<(), Failure> {
+    let arguments = Arguments::parse(args, &["from", "to", "output"])?;
+    let path = arguments.file()?;
+
+    let text = read(path)?;
+    let games = match arguments.option("from").unwrap_or("jsonl") {
+        "jsonl" => GameReader::new(text.as_bytes()).collect::<Result<Vec<_>, _>>().map_err(|e| Failure::Invalid(format!("{path}: {e}")))?,
+        "live-feed" => vec![Game::from_live_feed(&text).map_err(|e| Failure::Invalid(format!("{path}: {e}")))?],
+        "retrosheet" => import_retrosheet(&text).map_err(|e| Failure::Invalid(format!("{path}: {e}")))?,
+        format => return Err(Failure::Usage(format!("unknown input format {format:?}"))),
+    };
+    let output = match arguments.option("to") {
+        Some("jsonl") => games.iter().map(Game::to_jsonl).collect::<String>(),
+        Some("json") => {
+            let games = games.iter().map(Game::to_json).collect::<Vec<_>>();
+            let document = if let [game] = games.as_slice() { game.clone() } else { serde_json::Value::Array(games) };
+            format!("{document:#}\n")
+        }
+        Some("retrosheet") => games.iter().map(Game::to_retrosheet).collect::<Result<String, _>>()
+            .map_err(|e| Failure::Invalid(format!("{path}: {e}")))?,
+        Some(format) => return Err(Failure::Usage(format!("unknown output format {format:?}"))),
+        None => return Err(Failure::Usage("convert needs --to".to_string())),
+    };
+
+    match arguments.option("output") {
+        Some(output_path) => fs::write(output_path, output).map_err(|e| Failure::Invalid(format!("{output_path}: could not write: {e}"))),
+        None => {
+            print!("{output}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.split_first() {
+        Some((command, args)) => match command.as_str() {
+            "validate" => validate(args),
+            "inspect" => inspect(args),
+            "regex" => regex(args),
+            "convert" => convert(args),
+            "help" | "--help" | "-h" => {
+                println!("{USAGE}");
+                Ok(())
+            }
+            command => Err(Failure::Usage(format!("unknown command {command:?}"))),
+        },
+        None => Err(Failure::Usage("expected a command".to_string())),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Invalid(message)) => {
+            eprintln!("mlb-jsonl: {message}");
+            ExitCode::from(1)
+        }
+        Err(Failure::Usage(message)) => {
+            eprintln!("mlb-jsonl: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn valid_game_has_no_problems() {
+        assert!(validate_text(GAME).is_empty());
+    }
+
+    #[test]
+    fn problems_have_line_numbers() {
+        let mut lines = GAME.lines().collect::<Vec<_>>();
+        lines[2] = r#"{ "batter": "Nobody", "pitcher": "Hunter Greene" }"#;
+        lines[5] = "{ \"batter\": ";
+        let problems = validate_text(&format!("{}\n{GAME}", lines.join("\n")));
+
+        // The unknown batter is accepted as the runner of the next line, so the real runner is reported there too. The
+        // second game starts after the invalid line, and is checked on its own.
+        assert_eq!(problems.iter().map(|problem| problem.line_number).collect::<Vec<_>>(), vec![Some(3), Some(4), Some(6)]);
+        assert!(problems[0].message.starts_with("/batter: expected "));
+        assert!(problems[2].message.starts_with("/: expected valid JSON"));
+    }
+
+    #[test]
+    fn regex_lines_count_blank_lines() {
+        let mut lines = GAME.lines().collect::<Vec<_>>();
+        lines.insert(1, "");
+        let text = lines.join("\n");
+
+        let mut parser = Parser::new(false);
+        advance_lines(&mut parser, &text, Some(3)).unwrap();
+        let mut expected = Parser::new(false);
+        advance_lines(&mut expected, GAME, Some(2)).unwrap();
+        assert_eq!(parser.generate_regex(), expected.generate_regex());
+
+        lines[3] = "{ \"batter\": ";
+        let error = advance_lines(&mut Parser::new(false), &lines.join("\n"), None).unwrap_err();
+        assert_eq!(error.line_number, Some(4));
+    }
+
+    #[test]
+    fn game_summary() {
+        let game = Parser::new(false).parse_str(GAME).unwrap().remove(0);
+        let summary = describe(&game);
+
+        assert!(summary.starts_with("game 748236 on 2024-02-25 at Great American Ball Park"), "{summary}");
+        assert!(summary.contains(&format!("plays: {} (", game.plays.len())), "{summary}");
+        assert_eq!(score(&game), (4, 9));
+        assert_eq!(final_state(&game), "top of inning 9, 3 out(s), bases empty");
+        assert_eq!(last_half_inning(&game).last(), game.plays.last());
+        assert!(last_half_inning(&game).iter().all(|play| play.inning() == game.plays.last().unwrap().inning()));
+    }
+
+    #[test]
+    fn inspect_continues_past_unreadable_files() {
+        let files = ["test_data/missing.jsonl".to_string(), "test_data/748236.jsonl".to_string()];
+        match inspect(&files) {
+            Err(Failure::Invalid(message)) => assert_eq!(message, "some files or games could not be read"),
+            _ => panic!("a missing file should fail the inspection"),
+        }
+    }
+}
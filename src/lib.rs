@@ -2,12 +2,28 @@
 
 mod parser;
 
-pub use parser::Parser;
+pub use parser::{
+    Base, BattingLine, BattingStats, BattingTotals, BoxScore, Context, FormatProfile, Game, GameReader, Inning,
+    InningLine, JsonType, KeyValueType, LiveFeedError, MissingFields, Movement, Outcome, ParseError, Parser,
+    PitchingLine, Play, PlayField, PlayKind, PlayStream, PlayType, Player, ProfileError, RegexDialect, RegexStyle,
+    RetrosheetError, SchemaError, Team, TeamBoxScore, ToJsonSchema, ToRegex, Violation, Weather, bases_after_plays,
+    import_retrosheet, import_retrosheet_file, parse_game_file, parse_game_files,
+};
+#[cfg(feature = "python")]
+use parser::{PyJsonType, parse_games, py_import_retrosheet, schema_to_regex};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "python")]
 #[pymodule]
 fn mlb_jsonl_parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Parser>()?;
+    m.add_class::<Game>()?;
+    m.add_class::<BattingStats>()?;
+    m.add_class::<PyJsonType>()?;
+    m.add_function(wrap_pyfunction!(schema_to_regex, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_games, m)?)?;
+    m.add_function(wrap_pyfunction!(py_import_retrosheet, m)?)?;
 
     Ok(())
 }
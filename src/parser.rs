@@ -1,13 +1,50 @@
+mod batting_stats;
+mod box_score;
 mod game;
 mod json_schema;
-
-use game::{Context, Game, GameBuilder, Inning, Movement, Play, PlayType, Base};
-use json_schema::{JsonType, KeyValueType, ToRegex};
-use pyo3::{exceptions::PyValueError, pyclass, pymethods, PyResult};
+mod live_feed;
+mod names;
+mod outcome;
+mod profile;
+#[cfg(feature = "python")]
+mod python;
+mod reader;
+mod retrosheet;
+mod schema_tree;
+mod writer;
+
+pub use batting_stats::{BattingStats, BattingTotals};
+pub use box_score::{BattingLine, BoxScore, InningLine, PitchingLine, TeamBoxScore};
+pub use game::{
+    Base, Context, Game, Inning, MissingFields, Movement, Play, PlayField, PlayType, Player, Team, Weather, bases_after_plays,
+};
+use game::{GameBuilder, PlayBuilder};
+
+pub use live_feed::LiveFeedError;
+pub use outcome::Outcome;
+pub use profile::{FormatProfile, PlayKind, ProfileError};
+pub use json_schema::{JsonType, KeyValueType, RegexDialect, RegexStyle, SchemaError, ToJsonSchema, ToRegex, Violation};
+use json_schema::{json_string_literal_regex, unicode_text_regex};
+pub use reader::{GameReader, PlayStream};
+pub use retrosheet::{RetrosheetError, import_retrosheet, import_retrosheet_file};
+#[cfg(feature = "python")]
+pub use python::{PyJsonType, parse_games, py_import_retrosheet, schema_to_regex};
+use lru::LruCache;
+use names::{NameMatch, NameResolver};
+#[cfg(feature = "python")]
+use pyo3::pyclass;
+use rayon::prelude::*;
 use serde::Deserialize;
-use strum::IntoEnumIterator;
+use std::{fmt, fs, num::NonZeroUsize, path::{Path, PathBuf}};
+
+/// The characters other than letters that may appear in a player's name.
+const PLAYER_NAME_CHARACTERS: &str = ".'- ";
 
-const UNICODE_WORD_CHAR: &str = r"[a-zA-ZÀ-ÖØ-öø-ÿ.'\- ]";
+/// The number of regexes to keep in a parser's regex cache.
+const REGEX_CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::new(256).unwrap();
+
+/// The characters other than letters that may appear in a venue's name.
+const VENUE_NAME_CHARACTERS: &str = ".'&- 0123456789";
 
 /// The JSON schema for the `weather` section of the `GameSection::Context` section.
 fn context_section_weather_json() -> KeyValueType {
@@ -16,77 +53,53 @@ fn context_section_weather_json() -> KeyValueType {
         JsonType::Object(vec![
             JsonType::key_value(
                 "condition",
-                JsonType::string_with_regex(r"[a-zA-Z ]+"),
+                JsonType::string_with_regex(&unicode_text_regex(" ")),
             ),
             JsonType::key_value(
                 "temperature",
-                JsonType::integer_max_digits(3),
+                JsonType::integer_range(-30, 130),
             ),
             JsonType::key_value(
                 "wind_speed",
-                JsonType::integer_max_digits(3),
+                JsonType::integer_range(0, 150),
             ),
         ]),
     )
 }
 
-/// The positions that can be assigned to a player.
-const POSITIONS: [&str; 18] = [
-    "PITCHER",
-    "CATCHER",
-    "FIRST_BASE",
-    "SECOND_BASE",
-    "THIRD_BASE",
-    "SHORTSTOP",
-    "LEFT_FIELD",
-    "CENTER_FIELD",
-    "RIGHT_FIELD",
-    "DESIGNATED_HITTER",
-    "PINCH_HITTER",
-    "PINCH_RUNNER",
-    "TWO_WAY_PLAYER",
-    "OUTFIELD",
-    "INFIELD",
-    "UTILITY",
-    "RELIEF_PITCHER",
-    "STARTING_PITCHER",
-];
-
 /// The JSON schema for a `player` object in a team section of the `GameSection::Context` section.
-fn context_section_team_player_json() -> JsonType {
-    let position_regex = format!("({})", POSITIONS.join("|"));
-
+fn context_section_team_player_json(positions: &[String]) -> JsonType {
     JsonType::Object(vec![
         JsonType::key_value(
             "position",
-            JsonType::string_with_regex(&position_regex),
+            JsonType::string_enum(positions),
         ),
         JsonType::key_value(
             "name",
-            JsonType::string_with_regex(&format!("{UNICODE_WORD_CHAR}+")),
+            JsonType::string_with_regex(&unicode_text_regex(PLAYER_NAME_CHARACTERS)),
         ),
     ])
 }
 
 /// The JSON schema for a team object in the `GameSection::Context` section.
-fn context_section_team_json() -> JsonType {
+fn context_section_team_json(positions: &[String]) -> JsonType {
     JsonType::Object(vec![
         JsonType::key_value(
             "id",
-            JsonType::integer_max_digits(3),
+            JsonType::integer_range(1, 999),
         ),
         JsonType::key_value(
             "players",
-            JsonType::array(context_section_team_player_json()),
+            JsonType::array_with_constraints(context_section_team_player_json(positions), 1, None, false),
         ),
     ])
 }
 
 /// The JSON schema for a `Context` object.
-fn context_section_json() -> JsonType {
+fn context_section_json(positions: &[String]) -> JsonType {
     let game_pk = JsonType::key_value(
         "game_pk",
-        JsonType::integer_max_digits(6),
+        JsonType::integer_range(1, 999999),
     );
     let date = JsonType::key_value(
         "date",
@@ -94,16 +107,16 @@ fn context_section_json() -> JsonType {
     );
     let venue_name = JsonType::key_value(
         "venue_name",
-        JsonType::string_with_regex(&format!("{UNICODE_WORD_CHAR}+")),
+        JsonType::string_with_regex(&unicode_text_regex(VENUE_NAME_CHARACTERS)),
     );
 
     let home_team = JsonType::key_value(
         "home_team",
-        context_section_team_json(),
+        context_section_team_json(positions),
     );
     let away_team = JsonType::key_value(
         "away_team",
-        context_section_team_json(),
+        context_section_team_json(positions),
     );
 
     JsonType::object(vec![
@@ -117,7 +130,7 @@ fn context_section_json() -> JsonType {
 }
 
 /// The type of line being parsed.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum LineType {
     /// A line that contains the game's context.
     Context,
@@ -134,7 +147,7 @@ enum LineType {
 struct PlayIntroduction {
     inning: Inning,
     #[serde(rename = "type")]
-    play_type: PlayType,
+    play_type: String,
 }
 
 /// The contents of a play information object.
@@ -155,22 +168,87 @@ struct PlayMovements {
     movements: Vec<Movement>,
 }
 
+/// An error raised while parsing a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The number of the offending line, counting from 1, if the error is about a single line.
+    pub line_number: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            line_number: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line_number {
+            Some(line_number) => write!(f, "line {line_number}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// The parts of a parser's state that the regex for the next line depends on. Only the parts used by the next line's
+/// type are set, so that equivalent states share a cached regex.
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+struct RegexCacheKey {
+    line_type: Option<LineType>,
+    /// The inning of the last play for a play introduction, or the current play's inning otherwise.
+    inning: Option<(u32, bool)>,
+    play_type: Option<String>,
+    /// The runners on first, second and third base.
+    runners: [Option<String>; 3],
+    batter: Option<String>,
+    runner: Option<String>,
+    scoring_runner: Option<String>,
+}
+
 /// A streaming parser for the format described in `FORMAT.md`.
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Parser {
     /// Whether to print debug information.
     debug: bool,
     /// The type of line to be parsed next.
     line_type: LineType,
+    /// The number of lines parsed so far.
+    lines_parsed: usize,
+    /// The games that were completed by the context line of a following game, and not yet taken.
+    completed_games: Vec<Game>,
     /// The builder for the game.
-    pub game_builder: GameBuilder,
+    game_builder: GameBuilder,
+    /// Whether to canonicalize player names to their roster spelling instead of accepting them as written.
+    lenient: bool,
+    /// Resolves player names to their roster spelling in lenient mode.
+    name_resolver: NameResolver,
+    /// The warnings raised while parsing, such as names that were canonicalized in lenient mode.
+    warnings: Vec<String>,
+    /// Whether the generated regexes should also accept JSON-escaped forms of player names, such as `\u00e9` for `é`.
+    accept_json_escapes: bool,
+    /// How strictly the generated regexes follow the canonical layout of a line.
+    regex_style: RegexStyle,
+    /// The regex engine that the generated regexes are written for, or `None` for the portable syntax.
+    regex_dialect: Option<RegexDialect>,
+    /// The alternation of the home team's player names, built once the context has been parsed.
+    home_team_names_regex: Option<String>,
+    /// The alternation of the away team's player names, built once the context has been parsed.
+    away_team_names_regex: Option<String>,
+    /// The most recently generated regexes, keyed by the state they were generated for.
+    regex_cache: LruCache<RegexCacheKey, String>,
+    /// The play types and positions of the format being parsed.
+    profile: FormatProfile,
 }
 
 impl Parser {
     /// The JSON schema for a play introduction.
     fn play_introduction_json(&self) -> JsonType {
         let (valid_inning_numbers, valid_inning_tops) = if let Some(play) = self.game_builder.plays.last() {
-            let current_inning = play.get_inning();
+            let current_inning = play.inning();
             let next_inning = current_inning.next();
 
             let mut valid_inning_numbers = vec![current_inning.number as usize];
@@ -194,7 +272,7 @@ impl Parser {
         ]));
         let play_type = JsonType::key_value(
             "type",
-            JsonType::string_with_regex(&PlayType::iter().map(|play_type| play_type.to_string()).collect::<Vec<_>>().join("|")),
+            JsonType::string_enum(self.profile.play_types.iter().map(|play_kind| play_kind.name.clone())),
         );
 
         JsonType::object(vec![
@@ -206,22 +284,19 @@ impl Parser {
     /// Generates the JSON schema for a given `Movement` object.
     fn movement_json(&self, movement: &Movement) -> JsonType {
         JsonType::object(vec![
-            JsonType::key_value("runner", JsonType::string_with_regex(&movement.runner.replace(".", "\\."))),
-            JsonType::key_value("start_base", JsonType::string_with_regex(&movement.start_base.to_string())),
-            JsonType::key_value("end_base", JsonType::string_with_regex(&movement.end_base.to_string())),
+            JsonType::key_value("runner", JsonType::string_with_regex(&json_string_literal_regex(&movement.runner, self.accept_json_escapes))),
+            JsonType::key_value("start_base", JsonType::string_enum([movement.start_base.to_string()])),
+            JsonType::key_value("end_base", JsonType::string_enum([movement.end_base.to_string()])),
             JsonType::key_value("is_out", JsonType::boolean_with_options(vec![movement.is_out])),
         ])
     }
 
-    /// The JSON schema for an array of valid movement objects.
-    fn valid_movements_json(&self) -> JsonType {
+    /// The movements that may start the movements of a runner: those of the runners on base and the batter from where
+    /// they start the play, and those of pinch runners, the runner and the scoring runner.
+    fn valid_movements(&self) -> Vec<Movement> {
         let runner_positions = self.game_builder.runner_positions.clone();
         let players_bases = runner_positions.iter().filter_map(|(base, player)| {
-            if let Some(player) = player {
-                Some((player, base))
-            } else {
-                None
-            }
+            player.as_ref().map(|player| (player, base))
         }).collect::<Vec<_>>();
 
         let mut valid_movements: Vec<Movement> = Vec::new();
@@ -278,12 +353,12 @@ impl Parser {
             self.game_builder.away_team_pinch_runner_names()
         }.unwrap();
 
-        let pinch_movements = valid_movements.iter().map(|movement| pinch_runners.iter().map(|runner| Movement {
+        let pinch_movements = valid_movements.iter().flat_map(|movement| pinch_runners.iter().map(|runner| Movement {
             runner: runner.clone(),
             start_base: movement.start_base,
             end_base: movement.end_base,
             is_out: movement.is_out,
-        })).flatten().collect::<Vec<_>>();
+        })).collect::<Vec<_>>();
         valid_movements.extend(pinch_movements);
 
         // add the runner's and scoring runner's movements
@@ -323,10 +398,33 @@ impl Parser {
             }
         }
 
-        let valid_movements_json = valid_movements.iter().map(|movement| self.movement_json(movement)).collect::<Vec<_>>();
-        let valid_movements_json = JsonType::array(JsonType::Union(valid_movements_json));
+        valid_movements
+    }
+
+    /// The JSON schema for an array of valid movement objects. A runner who takes several bases in separate movements,
+    /// e.g. from 2 to 3 and then from 3 to home, may also start a movement from any base they can reach on the play;
+    /// since a regex cannot tell whether they reached it earlier in the array, `explain` checks that separately.
+    fn valid_movements_json(&self) -> JsonType {
+        let mut valid_movements = self.valid_movements();
+        let reached_bases = valid_movements.iter()
+            .filter(|movement| !movement.is_out && movement.end_base != Base::Home)
+            .map(|movement| (movement.runner.clone(), movement.end_base))
+            .collect::<Vec<_>>();
+        for (runner, base) in reached_bases {
+            let chained_movements = base.valid_to_bases().into_iter()
+                .flat_map(|end_base| [false, true].map(|is_out| (end_base, is_out)))
+                .chain([(base, true)])
+                .map(|(end_base, is_out)| Movement { runner: runner.clone(), start_base: base, end_base, is_out })
+                .collect::<Vec<_>>();
+            for movement in chained_movements {
+                if !valid_movements.contains(&movement) {
+                    valid_movements.push(movement);
+                }
+            }
+        }
 
-        valid_movements_json
+        let valid_movements_json = valid_movements.iter().map(|movement| self.movement_json(movement)).collect::<Vec<_>>();
+        JsonType::array(JsonType::Union(valid_movements_json))
     }
 
     /// The JSON schema for a movements line object. It is technically too broad, but it will do for now.
@@ -340,141 +438,204 @@ impl Parser {
     }
 
     /// Generates the JSON schema for a play information object with the given fields.
-    fn single_play_information_json(
-        &self,
-        needs_base: bool,
-        needs_batter: bool,
-        needs_pitcher: bool,
-        needs_catcher: bool,
-        needs_fielders: bool,
-        needs_runner: bool,
-        needs_scoring_runner: bool,
-        needs_movements: bool,
-    ) -> JsonType {
+    fn play_information_json(&self, fields: &[PlayField]) -> JsonType {
         let home_team_batting = !self.game_builder.play_builder.inning.unwrap().top; // if the inning is top, then the away team is batting
-        let home_team_player_names_regex = if let Some(names) = self.game_builder.home_team_player_names() {
-            names.iter().map(|name| format!("({name})").replace(".", "\\.")).collect::<Vec<_>>().join("|")
+        let player_names_regex = unicode_text_regex(PLAYER_NAME_CHARACTERS);
+        let home_team_player_names_regex = self.home_team_names_regex.as_ref().unwrap_or(&player_names_regex);
+        let away_team_player_names_regex = self.away_team_names_regex.as_ref().unwrap_or(&player_names_regex);
+        let (batting_team_player_names_regex, fielding_team_player_names_regex) = if home_team_batting {
+            (home_team_player_names_regex, away_team_player_names_regex)
         } else {
-            format!("{UNICODE_WORD_CHAR}+")
-        };
-        let away_team_player_names_regex = if let Some(names) = self.game_builder.away_team_player_names() {
-            names.iter().map(|name| format!("({name})").replace(".", "\\.")).collect::<Vec<_>>().join("|")
-        } else {
-            format!("{UNICODE_WORD_CHAR}+")
+            (away_team_player_names_regex, home_team_player_names_regex)
         };
 
-        let mut json_object = Vec::new();
-
-        if needs_base {
-            json_object.push(JsonType::key_value("base", JsonType::string_with_regex(r"home|1|2|3")));
-        }
-        if needs_batter {
-            json_object.push(JsonType::key_value("batter", JsonType::string_with_regex(if home_team_batting {
-                &home_team_player_names_regex
-            } else {
-                &away_team_player_names_regex
-            })));
-        }
-        if needs_pitcher {
-            json_object.push(JsonType::key_value("pitcher", JsonType::string_with_regex(if home_team_batting {
-                &away_team_player_names_regex
-            } else {
-                &home_team_player_names_regex
-            })));
-        }
-        if needs_catcher {
-            json_object.push(JsonType::key_value("catcher", JsonType::string_with_regex(if home_team_batting {
-                &away_team_player_names_regex
-            } else {
-                &home_team_player_names_regex
-            })));
-        }
-        if needs_fielders {
-            json_object.push(JsonType::key_value("fielders", JsonType::array(JsonType::string_with_regex(if home_team_batting {
-                &away_team_player_names_regex
-            } else {
-                &home_team_player_names_regex
-            }))));
-        }
-        if needs_runner {
-            json_object.push(JsonType::key_value("runner", JsonType::string_with_regex(if home_team_batting {
-                &home_team_player_names_regex
-            } else {
-                &away_team_player_names_regex
-            })));
-        }
-        if needs_scoring_runner {
-            json_object.push(JsonType::key_value("scoring_runner", JsonType::string_with_regex(if home_team_batting {
-                &home_team_player_names_regex
-            } else {
-                &away_team_player_names_regex
-            })));
-        }
-
-        JsonType::object(json_object)
-    }
-
-    /// Generates the JSON schema for a play information object for the given `PlayType`.
-    fn play_information_json_for_play_type(&self, play_type: &PlayType) -> JsonType {
-        match play_type {
-            PlayType::Groundout =>              self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::BuntGroundout =>          self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::Strikeout =>              self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::Lineout =>                self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::BuntLineout =>            self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::Flyout =>                 self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::PopOut =>                 self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::BuntPopOut =>             self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::Forceout =>               self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::FieldersChoiceOut =>      self.single_play_information_json(  false,  true,   true,   false,  true,   false,  true,   true),
-            PlayType::DoublePlay =>             self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::TriplePlay =>             self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::RunnerDoublePlay =>       self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::RunnerTriplePlay =>       self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::GroundedIntoDoublePlay => self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::StrikeoutDoublePlay =>    self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::Pickoff =>                self.single_play_information_json(  true,   false,  false,  false,  true,   true,   false,  true),
-            PlayType::PickoffError =>           self.single_play_information_json(  true,   false,  false,  false,  true,   true,   false,  true),
-            PlayType::CaughtStealing =>         self.single_play_information_json(  true,   false,  false,  false,  true,   true,   false,  true),
-            PlayType::PickoffCaughtStealing =>  self.single_play_information_json(  true,   false,  false,  false,  true,   true,   false,  true),
-            PlayType::WildPitch =>              self.single_play_information_json(  false,  false,  true,   false,  false,  true,   false,  true),
-            PlayType::RunnerOut =>              self.single_play_information_json(  false,  false,  false,  false,  true,   true,   false,  true),
-            PlayType::FieldOut =>               self.single_play_information_json(  false,  false,  false,  false,  true,   true,   false,  true),
-            PlayType::BatterOut =>              self.single_play_information_json(  false,  true,   false,  true,   false,  false,  false,  true),
-            PlayType::Balk =>                   self.single_play_information_json(  false,  false,  true,   false,  false,  false,  false,  true),
-            PlayType::PassedBall =>             self.single_play_information_json(  false,  false,  true,   true,   false,  false,  false,  true),
-            PlayType::Error =>                  self.single_play_information_json(  false,  false,  true,   true,   false,  false,  false,  true),
-            PlayType::Single =>                 self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::Double =>                 self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::Triple =>                 self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::HomeRun =>                self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::Walk =>                   self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::IntentWalk =>             self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::HitByPitch =>             self.single_play_information_json(  false,  true,   true,   false,  false,  false,  false,  true),
-            PlayType::FieldersChoice =>         self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::CatcherInterference =>    self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::StolenBase =>             self.single_play_information_json(  true,   false,  false,  false,  false,  true,   false,  true),
-            PlayType::SacFly =>                 self.single_play_information_json(  false,  true,   true,   false,  true,   false,  true,   true),
-            PlayType::SacFlyDoublePlay =>       self.single_play_information_json(  false,  true,   true,   false,  true,   false,  true,   true),
-            PlayType::SacBunt =>                self.single_play_information_json(  false,  true,   true,   false,  true,   true,   false,  true),
-            PlayType::SacBuntDoublePlay =>      self.single_play_information_json(  false,  true,   true,   false,  true,   true,   false,  true),
-            PlayType::FieldError =>             self.single_play_information_json(  false,  true,   true,   false,  true,   false,  false,  true),
-            PlayType::GameAdvisory =>           self.single_play_information_json(  false,  false,  false,  false,  false,  false,  false,  false),
-            PlayType::Ejection =>               self.single_play_information_json(  false,  false,  false,  false,  false,  false,  false,  true),
+        JsonType::object(fields.iter().filter(|field| field.is_information()).map(|field| {
+            let value = match field {
+                PlayField::Base => JsonType::string_enum(["home", "1", "2", "3"]),
+                PlayField::Batter | PlayField::Runner | PlayField::ScoringRunner => JsonType::string_with_regex(batting_team_player_names_regex),
+                PlayField::Pitcher | PlayField::Catcher => JsonType::string_with_regex(fielding_team_player_names_regex),
+                // fielders are not unique, since a fielder who fields the ball and makes the putout is listed twice
+                PlayField::Fielders => JsonType::array_with_constraints(JsonType::string_with_regex(fielding_team_player_names_regex), 1, None, false),
+                PlayField::Movements => unreachable!("movements are not on the information line"),
+            };
+            JsonType::key_value(field.key(), value)
+        }).collect())
+    }
+
+    /// Generates the schema for the next line to be parsed.
+    fn generate_schema(&self) -> JsonType {
+        match &self.line_type {
+            LineType::Context => context_section_json(&self.profile.positions),
+            LineType::PlayIntroduction => self.play_introduction_json(),
+            LineType::PlayInformation => self.play_information_json(&self.game_builder.play_builder.play_kind.as_ref().unwrap().information),
+            LineType::PlayMovements => self.movements_json(),
         }
     }
 
     /// Parses the given line as a `Context` object.
-    fn parse_context(&mut self, line: &str) {
-        let context: Context = serde_json::from_str(line).unwrap();
+    fn parse_context(&mut self, line: &str) -> Result<(), ParseError> {
+        let context: Context = serde_json::from_str(line).map_err(|e| ParseError::new(format!("invalid context: {e}")))?;
+        self.name_resolver.set_roster(&context);
         self.game_builder.add_context(context);
+        self.cache_team_names_regexes();
+
+        Ok(())
+    }
+
+    /// Builds the alternations of each team's player names, which do not change after the context, and clears the
+    /// regexes cached for the previous names.
+    fn cache_team_names_regexes(&mut self) {
+        let names_regex = |names: Vec<String>| {
+            names.iter().map(|name| format!("({})", json_string_literal_regex(name, self.accept_json_escapes))).collect::<Vec<_>>().join("|")
+        };
+        self.home_team_names_regex = self.game_builder.home_team_player_names().map(names_regex);
+        self.away_team_names_regex = self.game_builder.away_team_player_names().map(names_regex);
+        self.regex_cache.clear();
+    }
+
+    /// The parts of the parser's state that the next line's regex depends on.
+    fn regex_cache_key(&self) -> RegexCacheKey {
+        let play_builder = &self.game_builder.play_builder;
+        let current_inning = play_builder.inning.map(|inning| (inning.number, inning.top));
+
+        match self.line_type {
+            LineType::Context => RegexCacheKey { line_type: Some(LineType::Context), ..Default::default() },
+            LineType::PlayIntroduction => RegexCacheKey {
+                line_type: Some(LineType::PlayIntroduction),
+                inning: self.game_builder.plays.last().map(|play| (play.inning().number, play.inning().top)),
+                ..Default::default()
+            },
+            LineType::PlayInformation => RegexCacheKey {
+                line_type: Some(LineType::PlayInformation),
+                inning: current_inning,
+                play_type: play_builder.play_kind.as_ref().map(|play_kind| play_kind.name.clone()),
+                ..Default::default()
+            },
+            LineType::PlayMovements => RegexCacheKey {
+                line_type: Some(LineType::PlayMovements),
+                inning: current_inning,
+                runners: [Base::First, Base::Second, Base::Third].map(|base| {
+                    self.game_builder.runner_positions.get(&base).cloned().flatten()
+                }),
+                batter: play_builder.batter.clone(),
+                runner: play_builder.runner.clone(),
+                scoring_runner: play_builder.scoring_runner.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Whether `line` is the context line of a new game, rather than the introduction of the next play.
+    fn starts_new_game(&self, line: &str) -> bool {
+        self.line_type == LineType::PlayIntroduction
+            && serde_json::from_str::<PlayIntroduction>(line).is_err()
+            && serde_json::from_str::<Context>(line).is_ok()
+    }
+
+    /// Starts parsing a new game, keeping the parser's settings.
+    fn start_new_game(&mut self) {
+        self.line_type = LineType::Context;
+        self.game_builder = GameBuilder::new();
+        self.home_team_names_regex = None;
+        self.away_team_names_regex = None;
+        self.regex_cache.clear();
+    }
+
+    /// Moves the game parsed so far to the completed games, and starts a new one.
+    fn complete_game(&mut self) -> Result<(), ParseError> {
+        let game = self.finish().map_err(|e| ParseError::new(format!("the previous game could not be built: {e}")))?;
+        self.completed_games.push(game);
+        self.start_new_game();
+
+        Ok(())
+    }
+
+    /// Parses a line and updates the parser's state, without generating the next line's regex.
+    pub fn advance(&mut self, line: &str) -> Result<(), ParseError> {
+        self.lines_parsed += 1;
+        let line_number = self.lines_parsed;
+        self.advance_line_type(line).map_err(|e| ParseError { line_number: Some(line_number), ..e })
+    }
+
+    fn advance_line_type(&mut self, line: &str) -> Result<(), ParseError> {
+        match &self.line_type {
+            LineType::Context => {
+                self.parse_context(line)?;
+                self.line_type = LineType::PlayIntroduction;
+            }
+            LineType::PlayIntroduction if self.starts_new_game(line) => {
+                self.complete_game()?;
+                self.parse_context(line)?;
+                self.line_type = LineType::PlayIntroduction;
+            }
+            LineType::PlayIntroduction => {
+                self.parse_play_introduction(line)?;
+                if self.debug {
+                    println!("play_type: {:?}", self.game_builder.play_builder.play_type);
+                }
+                let play_kind = self.game_builder.play_builder.play_kind.as_ref().unwrap();
+                self.line_type = if play_kind.has_information_line() {
+                    LineType::PlayInformation
+                } else if play_kind.movements {
+                    LineType::PlayMovements
+                } else {
+                    self.complete_play()?;
+                    LineType::PlayIntroduction
+                };
+            }
+            LineType::PlayInformation => {
+                self.parse_play_information(line)?;
+                self.line_type = if self.game_builder.play_builder.play_kind.as_ref().unwrap().movements {
+                    LineType::PlayMovements
+                } else {
+                    self.complete_play()?;
+                    LineType::PlayIntroduction
+                };
+            }
+            LineType::PlayMovements => {
+                self.parse_play_movements(line)?;
+                self.complete_play()?;
+                self.line_type = LineType::PlayIntroduction;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses a line, updates the parser's state, and returns the next line's regex.
+    pub fn parse_line(&mut self, line: &str) -> Result<String, ParseError> {
+        self.advance(line)?;
+        Ok(self.generate_regex())
+    }
+
+    /// Returns the roster spelling of the given player name in lenient mode, recording a warning if it was changed.
+    fn canonicalize_name(&mut self, name: String) -> String {
+        if !self.lenient {
+            return name;
+        }
+
+        match self.name_resolver.resolve(&name) {
+            NameMatch::Exact => name,
+            NameMatch::Canonical(canonical) => {
+                self.warnings.push(format!("canonicalized player name {name:?} to {canonical:?}"));
+                canonical
+            }
+            NameMatch::Ambiguous(candidates) => {
+                self.warnings.push(format!("player name {name:?} is ambiguous between {candidates:?}, keeping it as written"));
+                name
+            }
+            NameMatch::Unknown => {
+                self.warnings.push(format!("player name {name:?} is not on either roster, keeping it as written"));
+                name
+            }
+        }
     }
 
     /// Adds the given play to the `GameBuilder`.
     fn add_play(&mut self, play: Play) {
         if let Some(last_play) = self.game_builder.plays.last() {
-            let last_inning = last_play.get_inning();
-            let play_inning = play.get_inning();
+            let last_inning = last_play.inning();
+            let play_inning = play.inning();
 
             // if a new half inning is starting, clear the runner positions
             if last_inning.top != play_inning.top {
@@ -485,121 +646,293 @@ impl Parser {
     }
 
     /// Parses the given line as a `PlayIntroduction` object.
-    fn parse_play_introduction(&mut self, line: &str) {
-        let play_introduction: PlayIntroduction = serde_json::from_str(line).unwrap();
+    fn parse_play_introduction(&mut self, line: &str) -> Result<(), ParseError> {
+        let play_introduction: PlayIntroduction = serde_json::from_str(line).map_err(|e| ParseError::new(format!("invalid play introduction: {e}")))?;
+        let play_kind = self.profile.play_kind(&play_introduction.play_type).cloned().ok_or_else(|| {
+            ParseError::new(format!("invalid play introduction: unknown play type {:?}", play_introduction.play_type))
+        })?;
         self.game_builder.play_builder.inning = Some(play_introduction.inning);
-        self.game_builder.play_builder.play_type = Some(play_introduction.play_type);
+        self.game_builder.play_builder.set_play_kind(play_kind);
 
-        if self.game_builder.play_builder.play_type.unwrap() == PlayType::GameAdvisory {
-            let play = self.game_builder.play_builder.build();
-            self.add_play(play);
-        }
+        Ok(())
     }
 
     /// Parses the given line as a `PlayInformation` object.
-    fn parse_play_information(&mut self, line: &str) {
+    fn parse_play_information(&mut self, line: &str) -> Result<(), ParseError> {
         if self.debug {
             println!("play_information: {line:?}");
         }
-        let play_information: PlayInformation = serde_json::from_str(line).unwrap();
+        let play_information: PlayInformation = serde_json::from_str(line).map_err(|e| ParseError::new(format!("invalid play information: {e}")))?;
+
+        let play_kind = self.game_builder.play_builder.play_kind.as_ref().expect("the play kind is set by the play introduction");
+        let given = [
+            (PlayField::Base, play_information.base.is_some()),
+            (PlayField::Batter, play_information.batter.is_some()),
+            (PlayField::Pitcher, play_information.pitcher.is_some()),
+            (PlayField::Catcher, play_information.catcher.is_some()),
+            (PlayField::Fielders, play_information.fielders.is_some()),
+            (PlayField::Runner, play_information.runner.is_some()),
+            (PlayField::ScoringRunner, play_information.scoring_runner.is_some()),
+        ];
+        let unexpected = given.iter()
+            .filter(|(field, given)| *given && !play_kind.information.contains(field))
+            .map(|(field, _)| field.key())
+            .collect::<Vec<_>>();
+        if !unexpected.is_empty() {
+            return Err(ParseError::new(format!(
+                "invalid play information: {}: unexpected {}",
+                play_kind.name,
+                unexpected.join(", "),
+            )));
+        }
 
         if let Some(base) = play_information.base {
             self.game_builder.play_builder.set_base(base);
         }
         if let Some(batter) = play_information.batter {
+            let batter = self.canonicalize_name(batter);
             self.game_builder.play_builder.set_batter(batter);
         }
         if let Some(pitcher) = play_information.pitcher {
+            let pitcher = self.canonicalize_name(pitcher);
             self.game_builder.play_builder.set_pitcher(pitcher);
         }
         if let Some(catcher) = play_information.catcher {
+            let catcher = self.canonicalize_name(catcher);
             self.game_builder.play_builder.set_catcher(catcher);
         }
         if let Some(fielders) = play_information.fielders {
+            let fielders = fielders.into_iter().map(|fielder| self.canonicalize_name(fielder)).collect();
             self.game_builder.play_builder.set_fielders(fielders);
         }
         if let Some(runner) = play_information.runner {
+            let runner = self.canonicalize_name(runner);
             self.game_builder.play_builder.set_runner(runner);
         }
         if let Some(scoring_runner) = play_information.scoring_runner {
+            let scoring_runner = self.canonicalize_name(scoring_runner);
             self.game_builder.play_builder.set_scoring_runner(scoring_runner);
         }
 
-        if self.game_builder.play_builder.play_type.unwrap() == PlayType::GameAdvisory {
-            let play = self.game_builder.play_builder.build();
-            self.add_play(play);
-        }
+        Ok(())
     }
 
     /// Parses the given line as a `PlayMovements` object.
-    fn parse_play_movements(&mut self, line: &str) {
-        let play_movements: PlayMovements = serde_json::from_str(line).unwrap();
-        self.game_builder.play_builder.set_movements(play_movements.movements);
+    fn parse_play_movements(&mut self, line: &str) -> Result<(), ParseError> {
+        let play_movements: PlayMovements = serde_json::from_str(line).map_err(|e| ParseError::new(format!("invalid play movements: {e}")))?;
+        let movements = play_movements.movements.into_iter().map(|movement| Movement {
+            runner: self.canonicalize_name(movement.runner),
+            ..movement
+        }).collect();
+        self.game_builder.play_builder.set_movements(movements);
+
+        Ok(())
+    }
 
-        let play = self.game_builder.play_builder.build();
+    /// Builds the play whose lines have all been parsed, adds it to the game, and resets the play builder for the next
+    /// play.
+    fn complete_play(&mut self) -> Result<(), ParseError> {
+        let play = self.game_builder.play_builder.build().map_err(|e| ParseError::new(format!("invalid play: {e}")))?;
         self.add_play(play);
+        self.game_builder.clear_play_builder();
+
+        Ok(())
     }
+
+    /// Parses every non-empty line of `text`, without generating any regexes, and builds its games, in order, after
+    /// any games completed before. Errors are reported with their line number in `text`, blank lines included.
+    pub fn parse_str(&mut self, text: &str) -> Result<Vec<Game>, ParseError> {
+        for (i, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            self.advance(line).map_err(|e| ParseError { line_number: Some(i + 1), ..e })?;
+        }
+
+        let game = self.finish()?;
+        let mut games = self.take_completed_games();
+        games.push(game);
+
+        Ok(games)
+    }
+}
+
+/// Parses the games in the file at `path`.
+pub fn parse_game_file(path: &Path) -> Result<Vec<Game>, ParseError> {
+    let text = fs::read_to_string(path).map_err(|e| ParseError::new(format!("could not read {}: {e}", path.display())))?;
+    Parser::new(false).parse_str(&text)
+}
+
+/// Parses the games in the files at `paths` in parallel, using `threads` threads or one per CPU.
+pub fn parse_game_files(paths: &[PathBuf], threads: Option<usize>) -> Result<Vec<Result<Vec<Game>, ParseError>>, rayon::ThreadPoolBuildError> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads.unwrap_or(0)).build()?;
+    Ok(pool.install(|| paths.par_iter().map(|path| parse_game_file(path)).collect()))
 }
 
-#[pymethods]
 impl Parser {
-    /// Creates a new parser. If `debug` is true, debug information will be printed during parsing.
-    #[new]
+    /// Creates a new parser for the MLB format described in FORMAT.md. If `debug` is true, debug information will be
+    /// printed during parsing.
     pub fn new(debug: bool) -> Self {
+        Self::with_profile(debug, FormatProfile::mlb())
+    }
+
+    /// Creates a new parser for the variant of the format described by `profile`.
+    pub fn with_profile(debug: bool, profile: FormatProfile) -> Self {
         Self {
             debug,
             line_type: LineType::Context,
+            lines_parsed: 0,
+            completed_games: Vec::new(),
             game_builder: GameBuilder::new(),
+            lenient: false,
+            name_resolver: NameResolver::new(),
+            warnings: Vec::new(),
+            accept_json_escapes: false,
+            regex_style: RegexStyle::Canonical,
+            regex_dialect: None,
+            home_team_names_regex: None,
+            away_team_names_regex: None,
+            regex_cache: LruCache::new(REGEX_CACHE_CAPACITY),
+            profile,
         }
     }
 
+    /// The play types and positions of the format being parsed.
+    pub fn profile(&self) -> &FormatProfile {
+        &self.profile
+    }
+
+    /// Sets how strictly the generated regexes follow the canonical layout of a line.
+    pub fn set_regex_style(&mut self, style: RegexStyle) {
+        self.regex_style = style;
+        self.regex_cache.clear();
+    }
+
+    /// Sets the regex engine that the generated regexes are written for, or `None` for the portable syntax shared by
+    /// all of them (the default).
+    pub fn set_regex_dialect(&mut self, dialect: Option<RegexDialect>) {
+        self.regex_dialect = dialect;
+        self.regex_cache.clear();
+    }
+
+    /// Sets whether the generated regexes should also accept JSON-escaped forms of player names, such as `\u00e9` for
+    /// `é` or `\"` for `"`.
+    pub fn set_accept_json_escapes(&mut self, accept_json_escapes: bool) {
+        self.accept_json_escapes = accept_json_escapes;
+        self.cache_team_names_regexes();
+    }
+
+    /// Sets whether player names should be canonicalized to their roster spelling, ignoring differences in accents,
+    /// case and punctuation. Every canonicalized name is recorded in `warnings`.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    /// Registers `alias` as another spelling of the roster name `canonical`, for use in lenient mode.
+    pub fn add_alias(&mut self, alias: &str, canonical: &str) {
+        self.name_resolver.add_alias(alias, canonical);
+    }
+
+    /// The warnings raised while parsing so far.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Generates the regex for the next line to be parsed.
-    fn generate_regex(&self) -> String {
-        match &self.line_type {
-            LineType::Context => context_section_json().to_regex(),
-            LineType::PlayIntroduction => self.play_introduction_json().to_regex(),
-            LineType::PlayInformation => self.play_information_json_for_play_type(&self.game_builder.play_builder.play_type.unwrap()).to_regex(),
-            LineType::PlayMovements => self.movements_json().to_regex(),
+    pub fn generate_regex(&mut self) -> String {
+        let key = self.regex_cache_key();
+        if let Some(regex) = self.regex_cache.get(&key) {
+            return regex.clone();
         }
+
+        let regex = self.generate_schema().to_regex_with(self.regex_style, self.regex_dialect);
+        self.regex_cache.put(key, regex.clone());
+        regex
     }
 
-    /// Parses a line, updates the parser's state, and returns the next line's regex.
-    pub fn parse_line(&mut self, line: &str) -> PyResult<String> {
-        match &self.line_type {
-            LineType::Context => {
-                self.parse_context(line);
-                self.line_type = LineType::PlayIntroduction;
-            }
-            LineType::PlayIntroduction => {
-                self.parse_play_introduction(line);
-                if self.debug {
-                    println!("play_type: {:?}", self.game_builder.play_builder.play_type);
+    /// Explains why the given line would be rejected as the next line, without updating the parser's state. Returns
+    /// every violation found, or an empty list if the line would be accepted.
+    pub fn explain(&self, line: &str) -> Vec<Violation> {
+        let value = match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(value) => value,
+            Err(e) => return vec![Violation {
+                pointer: String::new(),
+                expected: "valid JSON".to_string(),
+                found: format!("invalid JSON ({e})"),
+            }],
+        };
+
+        let schema = self.generate_schema();
+        let mut violations = schema.validate(&value);
+        if violations.is_empty() && self.line_type == LineType::PlayMovements {
+            violations = self.chained_movement_violations(&value);
+        }
+        if !violations.is_empty() {
+            return violations;
+        }
+
+        // the values are all valid, so if the regex still rejects the line, it must be laid out differently
+        let regex = schema.to_regex_with_style(self.regex_style);
+        let matches = regex::RegexBuilder::new(&format!("^({})$", RegexDialect::RustRegex.translate(&regex)))
+            .size_limit(1 << 30)
+            .build()
+            .is_ok_and(|regex| regex.is_match(line.trim_end_matches(['\r', '\n'])));
+        if matches {
+            Vec::new()
+        } else {
+            vec![Violation {
+                pointer: String::new(),
+                expected: format!("the layout matching {regex}"),
+                found: line.trim_end().to_string(),
+            }]
+        }
+    }
+
+    /// The movements of a movements line that start from a base their runner has not reached: a runner's first
+    /// movement starts from where they start the play, and each of their other movements from where the one before it
+    /// ended, if they were not out on it.
+    fn chained_movement_violations(&self, value: &serde_json::Value) -> Vec<Violation> {
+        let Ok(PlayMovements { movements }) = serde_json::from_value::<PlayMovements>(value.clone()) else {
+            return Vec::new();
+        };
+        let valid_movements = self.valid_movements();
+
+        let mut violations = Vec::new();
+        for (i, movement) in movements.iter().enumerate() {
+            let pointer = format!("/movements/{i}/start_base");
+            let found = serde_json::json!(movement.start_base);
+            match movements[..i].iter().rev().find(|other| other.runner == movement.runner) {
+                Some(previous) if previous.is_out => {
+                    violations.push(Violation::new(&pointer, format!("no movement after {} is out", movement.runner), &found));
                 }
-                self.line_type = match self.game_builder.play_builder.play_type.unwrap() {
-                    PlayType::GameAdvisory => LineType::PlayIntroduction,
-                    PlayType::Ejection => LineType::PlayMovements,
-                    _ => LineType::PlayInformation,
-                };
-            }
-            LineType::PlayInformation => {
-                self.parse_play_information(line);
-                self.line_type = LineType::PlayMovements;
-            }
-            LineType::PlayMovements => {
-                self.parse_play_movements(line);
-                self.line_type = LineType::PlayIntroduction;
+                Some(previous) if previous.end_base != movement.start_base => {
+                    violations.push(Violation::new(&pointer, format!("\"{}\", the base {} reached earlier", previous.end_base, movement.runner), &found));
+                }
+                Some(_) => {}
+                None if !valid_movements.iter().any(|other| other.runner == movement.runner && other.start_base == movement.start_base) => {
+                    violations.push(Violation::new(&pointer, format!("the base {} starts the play on", movement.runner), &found));
+                }
+                None => {}
             }
         }
 
-        Ok(self.generate_regex())
+        violations
     }
 
     /// Builds and returns the game.
-    pub fn finish(&self) -> PyResult<Game> {
-        match self.game_builder.build() {
-            Ok(game) => Ok(game),
-            Err(e) => Err(PyValueError::new_err(e)),
-        }
+    pub fn finish(&self) -> Result<Game, ParseError> {
+        self.game_builder.build().map_err(ParseError::new)
+    }
+
+    /// Returns the games completed so far, i.e. those followed by the context line of another game, and forgets them.
+    pub fn take_completed_games(&mut self) -> Vec<Game> {
+        std::mem::take(&mut self.completed_games)
+    }
+
+    /// Forgets the game being parsed, the completed games and the warnings, so that the next line is parsed as the
+    /// context of a new game. The parser's settings and aliases are kept.
+    pub fn reset(&mut self) {
+        self.start_new_game();
+        self.lines_parsed = 0;
+        self.completed_games.clear();
+        self.warnings.clear();
     }
 }
 
@@ -655,6 +988,29 @@ mod tests {
         assert_eq!(parser.line_type, LineType::PlayMovements);
     }
 
+    #[test]
+    fn play_builder_is_reset_after_each_play() {
+        let mut parser = Parser::new(false);
+
+        let _ = parser.parse_line("{ \"game_pk\": 123456, \"date\": \"2024-04-24\", \"venue_name\": \"Test Stadium\", \"weather\": { \"condition\": \"Sunny\", \"temperature\": 70, \"wind_speed\": 10 }, \"home_team\": { \"id\": 1, \"players\": [{ \"position\": \"PITCHER\", \"name\": \"John Doe\" }] }, \"away_team\": { \"id\": 2, \"players\": [{ \"position\": \"CATCHER\", \"name\": \"Jane Doe\" }] } }\n");
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Stolen Base\" }\n");
+        let _ = parser.parse_line("{ \"base\": \"2\", \"runner\": \"Jane Doe\" }\n");
+        let _ = parser.parse_line("{ \"movements\": [] }\n");
+        assert_eq!(parser.game_builder.plays.len(), 1);
+        assert_eq!(parser.game_builder.play_builder.runner, None);
+        assert_eq!(parser.game_builder.play_builder.play_kind, None);
+
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Single\" }\n");
+        let _ = parser.parse_line("{ \"batter\": \"Jane Doe\", \"pitcher\": \"John Doe\" }\n");
+        let _ = parser.parse_line("{ \"movements\": [] }\n");
+        assert_eq!(parser.game_builder.plays[1].runner(), None);
+
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Single\" }\n");
+        let error = parser.parse_line("{ \"batter\": \"Jane Doe\", \"pitcher\": \"John Doe\", \"runner\": \"Jane Doe\" }\n").unwrap_err();
+        assert_eq!(error.message, "invalid play information: Single: unexpected runner");
+        assert_eq!(error.line_number, Some(9));
+    }
+
     #[test]
     fn parse_game_advisory_does_not_expect_play_information_nor_movements() {
         let mut parser = Parser::new(true);
@@ -711,6 +1067,229 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn parse_lenient_canonicalizes_player_names() {
+        let mut parser = Parser::new(true);
+        parser.set_lenient(true);
+        parser.add_alias("Johnny Doe", "John Doe");
+
+        let _ = parser.parse_line("{ \"game_pk\": 123456, \"date\": \"2024-04-24\", \"venue_name\": \"Test Stadium\", \"weather\": { \"condition\": \"Sunny\", \"temperature\": 70, \"wind_speed\": 10 }, \"home_team\": { \"id\": 1, \"players\": [{ \"position\": \"PITCHER\", \"name\": \"John Doe\" }] }, \"away_team\": { \"id\": 2, \"players\": [{ \"position\": \"CATCHER\", \"name\": \"José Suárez\" }] } }\n");
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Walk\" }\n");
+        let _ = parser.parse_line("{ \"batter\": \"Jose Suarez\", \"pitcher\": \"Johnny Doe\" }\n");
+        let _ = parser.parse_line("{ \"movements\": [{ \"runner\": \"JOSE SUAREZ\", \"start_base\": \"home\", \"end_base\": \"1\", \"is_out\": false }] }\n");
+
+        assert_eq!(parser.game_builder.plays[0], Play::Walk {
+            inning: Inning { number: 1, top: true },
+            batter: "José Suárez".to_string(),
+            pitcher: "John Doe".to_string(),
+            movements: vec![Movement {
+                runner: "José Suárez".to_string(),
+                start_base: Base::Home,
+                end_base: Base::First,
+                is_out: false,
+            }],
+        });
+        assert_eq!(parser.warnings().len(), 3);
+    }
+
+    #[test]
+    fn regex_style_accepts_compact_and_reordered_lines() {
+        let mut parser = Parser::new(true);
+        parser.set_regex_style(RegexStyle::AnyKeyOrder { max_members: 3 });
+
+        let regex = parser.parse_line("{ \"game_pk\": 123456, \"date\": \"2024-04-24\", \"venue_name\": \"Test Stadium\", \"weather\": { \"condition\": \"Sunny\", \"temperature\": 70, \"wind_speed\": 10 }, \"home_team\": { \"id\": 1, \"players\": [{ \"position\": \"PITCHER\", \"name\": \"John Doe\" }] }, \"away_team\": { \"id\": 2, \"players\": [{ \"position\": \"CATCHER\", \"name\": \"Jane Doe\" }] } }\n").unwrap();
+        let regex = regex::Regex::new(&format!("^({regex})$")).unwrap();
+        assert!(regex.is_match("{\"inning\":{\"number\":1,\"top\":true},\"type\":\"Walk\"}"));
+        assert!(regex.is_match("{ \"type\": \"Walk\", \"inning\": { \"top\": true, \"number\": 1 } }"));
+
+        let _ = parser.parse_line("{\"type\":\"Walk\",\"inning\":{\"top\":true,\"number\":1}}");
+        assert_eq!(parser.line_type, LineType::PlayInformation);
+
+        assert!(RegexStyle::from_name("sloppy", 3).is_err());
+    }
+
+    #[test]
+    fn explain_reports_violations_without_changing_state() {
+        let mut parser = Parser::new(true);
+        let _ = parser.parse_line("{ \"game_pk\": 123456, \"date\": \"2024-04-24\", \"venue_name\": \"Test Stadium\", \"weather\": { \"condition\": \"Sunny\", \"temperature\": 70, \"wind_speed\": 10 }, \"home_team\": { \"id\": 1, \"players\": [{ \"position\": \"PITCHER\", \"name\": \"John Doe\" }] }, \"away_team\": { \"id\": 2, \"players\": [{ \"position\": \"CATCHER\", \"name\": \"Jane Doe\" }] } }\n");
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Walk\" }\n");
+
+        let violations = parser.explain("{ \"batter\": \"Jane Doe\", \"pitcher\": \"Nobody\" }");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].pointer, "/pitcher");
+        assert_eq!(violations[0].found, "\"Nobody\"");
+
+        assert!(parser.explain("{ \"batter\": \"Jane Doe\", \"pitcher\": \"John Doe\" }").is_empty());
+        assert_eq!(parser.explain("{\"batter\":\"Jane Doe\",\"pitcher\":\"John Doe\"}")[0].pointer, "");
+        assert_eq!(parser.explain("{ \"batter\": ")[0].expected, "valid JSON");
+        assert_eq!(parser.line_type, LineType::PlayInformation);
+    }
+
+    #[test]
+    fn chained_movements_start_where_the_runner_reached() {
+        let mut parser = Parser::new(false);
+        let _ = parser.parse_line("{ \"game_pk\": 123456, \"date\": \"2024-04-24\", \"venue_name\": \"Test Stadium\", \"weather\": { \"condition\": \"Sunny\", \"temperature\": 70, \"wind_speed\": 10 }, \"home_team\": { \"id\": 1, \"players\": [{ \"position\": \"PITCHER\", \"name\": \"John Doe\" }] }, \"away_team\": { \"id\": 2, \"players\": [{ \"position\": \"CATCHER\", \"name\": \"Jane Doe\" }] } }\n");
+        let _ = parser.parse_line("{ \"inning\": { \"number\": 1, \"top\": true }, \"type\": \"Double\" }\n");
+        let _ = parser.parse_line("{ \"batter\": \"Jane Doe\", \"pitcher\": \"John Doe\" }\n");
+        let movements = |legs: &[(&str, &str, bool)]| {
+            let legs = legs.iter().map(|(start, end, is_out)| {
+                format!("{{ \"runner\": \"Jane Doe\", \"start_base\": \"{start}\", \"end_base\": \"{end}\", \"is_out\": {is_out} }}")
+            });
+            format!("{{ \"movements\": [{}] }}", legs.collect::<Vec<_>>().join(", "))
+        };
+
+        assert!(parser.explain(&movements(&[("home", "1", false), ("1", "2", false)])).is_empty());
+        let violations = parser.explain(&movements(&[("1", "2", false)]));
+        assert_eq!((violations[0].pointer.as_str(), violations[0].expected.as_str()), ("/movements/0/start_base", "the base Jane Doe starts the play on"));
+        let violations = parser.explain(&movements(&[("home", "1", false), ("2", "3", false)]));
+        assert_eq!((violations[0].pointer.as_str(), violations[0].expected.as_str()), ("/movements/1/start_base", "\"1\", the base Jane Doe reached earlier"));
+        let violations = parser.explain(&movements(&[("home", "1", true), ("1", "2", false)]));
+        assert_eq!(violations[0].expected, "no movement after Jane Doe is out");
+    }
+
+    #[test]
+    fn rust_regex_dialect_matches_every_line_of_a_game() {
+        let mut parser = Parser::new(false);
+        parser.set_regex_dialect(Some(RegexDialect::RustRegex));
+
+        let game = include_str!("../test_data/748236.jsonl");
+        let mut lines = game.lines();
+        let mut regex = parser.parse_line(lines.next().unwrap()).unwrap();
+        for line in lines {
+            let compiled = regex::RegexBuilder::new(&format!("^({regex})$")).size_limit(1 << 30).build().unwrap();
+            assert!(compiled.is_match(line), "{line} does not match {regex}");
+            regex = parser.parse_line(line).unwrap();
+        }
+
+        assert!(RegexDialect::from_name("posix").is_err());
+    }
+
+    #[test]
+    fn parser_with_profile_builds_custom_plays() {
+        let mut profile = FormatProfile::mlb();
+        profile.play_types.push(PlayKind { name: "Bunt Single".to_string(), information: vec![PlayField::Batter, PlayField::Pitcher], movements: true });
+        profile.play_types.push(PlayKind { name: "Mercy Rule".to_string(), information: Vec::new(), movements: false });
+        let mut parser = Parser::with_profile(false, profile);
+        parser.set_regex_dialect(Some(RegexDialect::RustRegex));
+
+        let context = include_str!("../test_data/748236.jsonl").lines().next().unwrap();
+        let text = [
+            context,
+            r#"{ "inning": { "number": 1, "top": true }, "type": "Bunt Single" }"#,
+            r#"{ "batter": "Nelson Rada", "pitcher": "Hunter Greene" }"#,
+            r#"{ "movements": [{ "runner": "Nelson Rada", "start_base": "home", "end_base": "1", "is_out": false }] }"#,
+            r#"{ "inning": { "number": 1, "top": true }, "type": "Mercy Rule" }"#,
+        ].map(|line| format!("{line}\n")).concat();
+
+        let mut lines = text.lines();
+        let mut regex = parser.parse_line(lines.next().unwrap()).unwrap();
+        for line in lines {
+            let compiled = regex::RegexBuilder::new(&format!("^({regex})$")).size_limit(1 << 30).build().unwrap();
+            assert!(compiled.is_match(line), "{line} does not match {regex}");
+            regex = parser.parse_line(line).unwrap();
+        }
+
+        let game = parser.finish().unwrap();
+        assert_eq!(game.plays[0].name(), "Bunt Single");
+        assert_eq!(game.plays[0].play_type(), None);
+        assert_eq!(game.plays[0].movements().unwrap()[0].runner, "Nelson Rada");
+        assert!(matches!(&game.plays[1], Play::Custom { name, movements: None, .. } if name == "Mercy Rule"));
+        assert_eq!(game.to_jsonl(), text);
+
+        let error = Parser::new(false).parse_str(&text).unwrap_err();
+        assert_eq!(error.to_string(), r#"line 2: invalid play introduction: unknown play type "Bunt Single""#);
+    }
+
+    #[test]
+    fn cached_regexes_match_generated_regexes() {
+        let mut parser = Parser::new(false);
+
+        let game = include_str!("../test_data/748236.jsonl");
+        for line in game.lines() {
+            let regex = parser.parse_line(line).unwrap();
+            assert_eq!(regex, parser.generate_schema().to_regex());
+        }
+        assert!(parser.regex_cache.len() < game.lines().count());
+
+        parser.set_regex_style(RegexStyle::FlexibleWhitespace);
+        assert!(parser.regex_cache.is_empty());
+        assert_eq!(parser.generate_regex(), parser.generate_schema().to_regex_with_style(RegexStyle::FlexibleWhitespace));
+    }
+
+    #[test]
+    fn parse_line_without_regex() {
+        let mut parser = Parser::new(false);
+
+        let game = include_str!("../test_data/748236.jsonl");
+        for line in game.lines() {
+            parser.advance(line).unwrap();
+        }
+
+        assert!(parser.regex_cache.is_empty());
+        assert_eq!(parser.game_builder.plays.len(), 78);
+    }
+
+    #[test]
+    fn parse_str_reports_the_offending_line() {
+        let game = include_str!("../test_data/748236.jsonl");
+        assert_eq!(Parser::new(false).parse_str(game).unwrap().remove(0).plays.len(), 78);
+
+        let mut lines = game.lines().collect::<Vec<_>>();
+        lines[2] = "{ \"batter\": ";
+        let error = Parser::new(false).parse_str(&lines.join("\n")).unwrap_err();
+        assert_eq!(error.line_number, Some(3));
+        assert!(error.message.starts_with("invalid play information"));
+
+        // blank lines are skipped, but still counted
+        lines.splice(1..1, ["", "  "]);
+        assert_eq!(Parser::new(false).parse_str(&lines.join("\n")).unwrap_err().line_number, Some(5));
+    }
+
+    #[test]
+    fn parse_game_files_in_parallel() {
+        let paths = [PathBuf::from("test_data/748236.jsonl"), PathBuf::from("test_data/missing.jsonl")];
+        let games = parse_game_files(&paths, Some(2)).unwrap();
+
+        assert_eq!(games[0].as_ref().unwrap()[0].plays.len(), 78);
+        assert!(games[1].as_ref().unwrap_err().message.starts_with("could not read test_data/missing.jsonl"));
+    }
+
+    #[test]
+    fn parse_str_returns_every_game() {
+        let game = include_str!("../test_data/748236.jsonl");
+        let games = Parser::new(false).parse_str(&format!("{game}\n{game}")).unwrap();
+        assert_eq!(games.iter().map(|game| game.plays.len()).collect::<Vec<_>>(), [78, 78]);
+
+        // a game without plays cannot be built
+        let context = game.lines().next().unwrap();
+        let error = Parser::new(false).parse_str(&format!("{context}\n{game}")).unwrap_err();
+        assert_eq!(error.line_number, Some(2));
+        assert!(error.message.starts_with("the previous game could not be built"), "{error}");
+    }
+
+    #[test]
+    fn parser_completes_a_game_at_the_next_context_line() {
+        let game = include_str!("../test_data/748236.jsonl");
+        let mut parser = Parser::new(false);
+        parser.set_lenient(true);
+
+        for line in game.lines().chain(game.lines()) {
+            parser.advance(line).unwrap();
+        }
+
+        let completed_games = parser.take_completed_games();
+        assert_eq!(completed_games.len(), 1);
+        assert_eq!(completed_games[0].plays.len(), 78);
+        assert_eq!(parser.finish().unwrap().plays.len(), 78);
+        assert!(parser.take_completed_games().is_empty());
+
+        parser.reset();
+        assert_eq!(parser.line_type, LineType::Context);
+        assert!(parser.finish().is_err());
+        assert!(parser.lenient);
+        assert_eq!(parser.parse_str(game).unwrap().remove(0).plays.len(), 78);
+    }
+
     #[test]
     fn parse_entire_game() {
         let mut parser = Parser::new(true);
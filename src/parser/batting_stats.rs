@@ -0,0 +1,276 @@
+use super::{Base, Game, Movement, Outcome, ParseError, parse_game_file};
+#[cfg(feature = "python")]
+use pyo3::pyclass;
+use rayon::prelude::*;
+use std::{collections::HashMap, path::PathBuf};
+
+/// A player's batting totals over the plate appearances added to them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct BattingTotals {
+    pub plate_appearances: u32,
+    pub at_bats: u32,
+    pub hits: u32,
+    pub singles: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    /// Walks, including intentional walks.
+    pub walks: u32,
+    pub intentional_walks: u32,
+    pub hit_by_pitches: u32,
+    pub strikeouts: u32,
+    pub sac_flies: u32,
+    pub sac_bunts: u32,
+    pub grounded_into_double_plays: u32,
+    pub runs: u32,
+    pub runs_batted_in: u32,
+}
+
+/// `numerator / denominator`, or 0 if the denominator is 0.
+fn ratio(numerator: u32, denominator: u32) -> f64 {
+    if denominator == 0 { 0.0 } else { f64::from(numerator) / f64::from(denominator) }
+}
+
+/// Whether a movement is a run scoring.
+fn scores(movement: &&Movement) -> bool {
+    movement.end_base == Base::Home && !movement.is_out
+}
+
+impl BattingTotals {
+    /// Adds a plate appearance with the given outcome, on which `runs` runs scored.
+    fn add_plate_appearance(&mut self, outcome: Outcome, runs: u32) {
+        self.plate_appearances += 1;
+        self.at_bats += u32::from(outcome.is_at_bat());
+        self.hits += u32::from(outcome.is_hit());
+        self.singles += u32::from(outcome == Outcome::Single);
+        self.doubles += u32::from(outcome == Outcome::Double);
+        self.triples += u32::from(outcome == Outcome::Triple);
+        self.home_runs += u32::from(outcome == Outcome::HomeRun);
+        self.walks += u32::from(outcome.is_walk());
+        self.intentional_walks += u32::from(outcome == Outcome::IntentionalWalk);
+        self.hit_by_pitches += u32::from(outcome == Outcome::HitByPitch);
+        self.strikeouts += u32::from(outcome == Outcome::Strikeout);
+        self.sac_flies += u32::from(outcome == Outcome::SacFly);
+        self.sac_bunts += u32::from(outcome == Outcome::SacBunt);
+        self.grounded_into_double_plays += u32::from(outcome == Outcome::GroundedIntoDoublePlay);
+        if outcome.credits_runs_batted_in() {
+            self.runs_batted_in += runs;
+        }
+    }
+
+    /// Adds another set of totals, such as the same player's in another game.
+    pub fn add(&mut self, other: &Self) {
+        self.plate_appearances += other.plate_appearances;
+        self.at_bats += other.at_bats;
+        self.hits += other.hits;
+        self.singles += other.singles;
+        self.doubles += other.doubles;
+        self.triples += other.triples;
+        self.home_runs += other.home_runs;
+        self.walks += other.walks;
+        self.intentional_walks += other.intentional_walks;
+        self.hit_by_pitches += other.hit_by_pitches;
+        self.strikeouts += other.strikeouts;
+        self.sac_flies += other.sac_flies;
+        self.sac_bunts += other.sac_bunts;
+        self.grounded_into_double_plays += other.grounded_into_double_plays;
+        self.runs += other.runs;
+        self.runs_batted_in += other.runs_batted_in;
+    }
+
+    pub fn total_bases(&self) -> u32 {
+        self.singles + 2 * self.doubles + 3 * self.triples + 4 * self.home_runs
+    }
+
+    /// The batting average, `H / AB`, or 0 without at bats, as are the other rates.
+    pub fn average(&self) -> f64 {
+        ratio(self.hits, self.at_bats)
+    }
+
+    /// The on-base percentage, `(H + BB + HBP) / (AB + BB + HBP + SF)`.
+    pub fn on_base_percentage(&self) -> f64 {
+        ratio(self.hits + self.walks + self.hit_by_pitches, self.at_bats + self.walks + self.hit_by_pitches + self.sac_flies)
+    }
+
+    /// The slugging percentage, `TB / AB`.
+    pub fn slugging_percentage(&self) -> f64 {
+        ratio(self.total_bases(), self.at_bats)
+    }
+
+    /// On-base plus slugging, `OBP + SLG`.
+    pub fn on_base_plus_slugging(&self) -> f64 {
+        self.on_base_percentage() + self.slugging_percentage()
+    }
+}
+
+/// Batting statistics accumulated over any number of games: each player's totals, and their totals broken down by
+/// team and by game. Players are told apart by name, as they are in the format.
+///
+/// A plate appearance's outcome is its play type's, as mapped by `PlayType::outcome`; plays that do not end a plate
+/// appearance, such as stolen bases, only count for the runs scored on them. Runs batted in follow
+/// `Outcome::credits_runs_batted_in`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct BattingStats {
+    players: HashMap<String, BattingTotals>,
+    teams: HashMap<(u32, String), BattingTotals>,
+    games: HashMap<(u32, String), BattingTotals>,
+}
+
+impl BattingStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the plate appearances and runs of every player in `game`.
+    pub fn add_game(&mut self, game: &Game) {
+        let mut totals: HashMap<(u32, &str), BattingTotals> = HashMap::new();
+        for play in &game.plays {
+            let team_id = if play.inning().top { game.context.away_team.id } else { game.context.home_team.id };
+            let movements = play.movements().unwrap_or_default();
+            if let (Some(batter), Some(outcome)) = (play.batter(), play.play_type().and_then(|play_type| play_type.outcome())) {
+                let runs = movements.iter().filter(scores).count() as u32;
+                totals.entry((team_id, batter)).or_default().add_plate_appearance(outcome, runs);
+            }
+            for movement in movements.iter().filter(scores) {
+                totals.entry((team_id, &movement.runner)).or_default().runs += 1;
+            }
+        }
+
+        for ((team_id, name), line) in totals {
+            self.players.entry(name.to_string()).or_default().add(&line);
+            self.teams.entry((team_id, name.to_string())).or_default().add(&line);
+            self.games.entry((game.context.game_pk, name.to_string())).or_default().add(&line);
+        }
+    }
+
+    /// Adds the statistics accumulated by another `BattingStats`, such as one run over other games on another thread.
+    pub fn merge(&mut self, other: Self) {
+        for (name, line) in other.players {
+            self.players.entry(name).or_default().add(&line);
+        }
+        for (key, line) in other.teams {
+            self.teams.entry(key).or_default().add(&line);
+        }
+        for (key, line) in other.games {
+            self.games.entry(key).or_default().add(&line);
+        }
+    }
+
+    /// Accumulates the statistics of the games in the files at `paths` in parallel, using `threads` threads or one per
+    /// CPU, without keeping the games in memory. Returns the statistics of the games that could be parsed, and the
+    /// path and error of each file that could not.
+    pub fn from_files(paths: &[PathBuf], threads: Option<usize>) -> Result<(Self, Vec<(PathBuf, ParseError)>), rayon::ThreadPoolBuildError> {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads.unwrap_or(0)).build()?;
+
+        Ok(pool.install(|| {
+            paths.par_iter()
+                .fold(|| (Self::new(), Vec::new()), |(mut stats, mut errors), path| {
+                    match parse_game_file(path) {
+                        Ok(games) => games.iter().for_each(|game| stats.add_game(game)),
+                        Err(e) => errors.push((path.clone(), e)),
+                    }
+                    (stats, errors)
+                })
+                .reduce(|| (Self::new(), Vec::new()), |(mut stats, mut errors), (other_stats, other_errors)| {
+                    stats.merge(other_stats);
+                    errors.extend(other_errors);
+                    (stats, errors)
+                })
+        }))
+    }
+
+    /// Each player's totals, by name.
+    pub fn players(&self) -> &HashMap<String, BattingTotals> {
+        &self.players
+    }
+
+    /// Each player's totals for each team they batted for, by team id and name.
+    pub fn teams(&self) -> &HashMap<(u32, String), BattingTotals> {
+        &self.teams
+    }
+
+    /// Each player's totals in each game, by `game_pk` and name.
+    pub fn games(&self) -> &HashMap<(u32, String), BattingTotals> {
+        &self.games
+    }
+
+    pub fn player(&self, name: &str) -> Option<&BattingTotals> {
+        self.players.get(name)
+    }
+
+    /// The totals of all of a team's batters.
+    pub fn team_totals(&self, team_id: u32) -> BattingTotals {
+        let mut totals = BattingTotals::default();
+        for line in self.teams.iter().filter(|((id, _), _)| *id == team_id).map(|(_, line)| line) {
+            totals.add(line);
+        }
+
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn totals_match_the_box_score() {
+        let game = Parser::new(false).parse_str(GAME).unwrap().remove(0);
+        let mut stats = BattingStats::new();
+        stats.add_game(&game);
+        let box_score = game.box_score();
+
+        for (team_id, lines) in [(108, &box_score.away.batting), (113, &box_score.home.batting)] {
+            for line in lines {
+                let totals = &stats.teams()[&(team_id, line.name.clone())];
+                assert_eq!(
+                    (totals.at_bats, totals.runs, totals.hits, totals.home_runs, totals.runs_batted_in, totals.walks, totals.strikeouts),
+                    (line.at_bats, line.runs, line.hits, line.home_runs, line.runs_batted_in, line.walks, line.strikeouts),
+                    "{}",
+                    line.name,
+                );
+            }
+        }
+        assert_eq!(stats.team_totals(108).runs, 4);
+        assert_eq!(stats.team_totals(113).runs, 9);
+        assert_eq!(stats.team_totals(113).grounded_into_double_plays, 4);
+        assert_eq!(stats.games()[&(748236, "Luke Maile".to_string())], stats.players()["Luke Maile"]);
+    }
+
+    #[test]
+    fn slash_line() {
+        let totals = BattingTotals {
+            plate_appearances: 10,
+            at_bats: 8,
+            hits: 3,
+            singles: 1,
+            doubles: 1,
+            home_runs: 1,
+            walks: 1,
+            hit_by_pitches: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(totals.average(), 0.375);
+        assert_eq!(totals.on_base_percentage(), 0.5);
+        assert_eq!(totals.slugging_percentage(), 0.875);
+        assert_eq!(totals.on_base_plus_slugging(), 1.375);
+        assert_eq!(BattingTotals::default().average(), 0.0);
+    }
+
+    #[test]
+    fn statistics_are_accumulated_from_files() {
+        let paths = [PathBuf::from("test_data/748236.jsonl"), PathBuf::from("test_data/748236.jsonl"), PathBuf::from("test_data/missing.jsonl")];
+        let (stats, errors) = BattingStats::from_files(&paths, Some(2)).unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, paths[2]);
+        assert_eq!(stats.player("Luke Maile").unwrap().home_runs, 2);
+        assert_eq!(stats.team_totals(108).runs, 8);
+    }
+}
@@ -0,0 +1,321 @@
+use super::{Base, Game, Outcome};
+#[cfg(feature = "python")]
+use pyo3::pyclass;
+use std::{collections::HashMap, fmt};
+
+/// A batter's line in a box score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct BattingLine {
+    pub name: String,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub runs_batted_in: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+}
+
+/// A pitcher's line in a box score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct PitchingLine {
+    pub name: String,
+    /// The outs recorded while the pitcher was in the game, from which their innings pitched follow.
+    pub outs: u32,
+    pub hits: u32,
+    /// The runs scored by the runners the pitcher let on base.
+    pub runs: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+    pub home_runs: u32,
+}
+
+impl PitchingLine {
+    /// The pitcher's innings pitched as usually written, with thirds of an inning after the point: `5.2` for 17 outs.
+    pub fn innings_pitched(&self) -> String {
+        format!("{}.{}", self.outs / 3, self.outs % 3)
+    }
+}
+
+/// A team's runs, hits and errors in one inning of a linescore.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct InningLine {
+    /// The team's runs, or `None` if it did not bat in the inning, as the home team does not in the last inning of a
+    /// game it leads.
+    pub runs: Option<u32>,
+    pub hits: u32,
+    /// The errors the team made in the field.
+    pub errors: u32,
+}
+
+/// One team's half of a box score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct TeamBoxScore {
+    pub team_id: u32,
+    /// The team's batters, in the order they first came to the plate or, for pinch runners, scored.
+    pub batting: Vec<BattingLine>,
+    /// The team's pitchers, in the order they entered the game.
+    pub pitching: Vec<PitchingLine>,
+    /// The team's linescore, one line per inning of the game.
+    pub innings: Vec<InningLine>,
+}
+
+impl TeamBoxScore {
+    fn new(team_id: u32) -> Self {
+        Self { team_id, ..Default::default() }
+    }
+
+    pub fn runs(&self) -> u32 {
+        self.innings.iter().filter_map(|inning| inning.runs).sum()
+    }
+
+    pub fn hits(&self) -> u32 {
+        self.innings.iter().map(|inning| inning.hits).sum()
+    }
+
+    pub fn errors(&self) -> u32 {
+        self.innings.iter().map(|inning| inning.errors).sum()
+    }
+
+    fn batting_line(&mut self, name: &str) -> &mut BattingLine {
+        let i = self.batting.iter().position(|line| line.name == name).unwrap_or_else(|| {
+            self.batting.push(BattingLine { name: name.to_string(), ..Default::default() });
+            self.batting.len() - 1
+        });
+        &mut self.batting[i]
+    }
+
+    fn pitching_line(&mut self, name: &str) -> &mut PitchingLine {
+        let i = self.pitching.iter().position(|line| line.name == name).unwrap_or_else(|| {
+            self.pitching.push(PitchingLine { name: name.to_string(), ..Default::default() });
+            self.pitching.len() - 1
+        });
+        &mut self.pitching[i]
+    }
+
+    fn inning(&mut self, number: u32) -> &mut InningLine {
+        let number = number.max(1) as usize;
+        if self.innings.len() < number {
+            self.innings.resize(number, InningLine::default());
+        }
+        &mut self.innings[number - 1]
+    }
+}
+
+/// The box score of a game: each team's batting and pitching lines and its linescore, derived from the plays' types,
+/// batters and pitchers, and movements.
+///
+/// A plate appearance's outcome is its play type's, as mapped by `PlayType::outcome`. A runner who crosses home scores
+/// a run, which is batted in by the batter if `Outcome::credits_runs_batted_in` says so, and which is charged to the
+/// pitcher who let the runner on base. Outs are charged to the pitcher in the game, who is the last one named by a play
+/// of the half inning's team in the field. Earned runs are not told apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct BoxScore {
+    pub away: TeamBoxScore,
+    pub home: TeamBoxScore,
+}
+
+impl BoxScore {
+    pub fn new(game: &Game) -> Self {
+        let mut teams = [TeamBoxScore::new(game.context.away_team.id), TeamBoxScore::new(game.context.home_team.id)];
+        let mut pitchers: [Option<&str>; 2] = [None, None];
+        // the pitcher who let each runner on base
+        let mut responsible_pitchers = HashMap::new();
+        let mut last_inning = None;
+
+        for play in &game.plays {
+            let inning = *play.inning();
+            if last_inning != Some(inning) {
+                last_inning = Some(inning);
+                responsible_pitchers.clear();
+            }
+            let (batting, fielding) = if inning.top { (0, 1) } else { (1, 0) };
+            teams[batting].inning(inning.number).runs.get_or_insert(0);
+            teams[fielding].inning(inning.number);
+            if let Some(pitcher) = play.pitcher() {
+                pitchers[fielding] = Some(pitcher);
+                teams[fielding].pitching_line(pitcher);
+            }
+            let pitcher = pitchers[fielding];
+            let play_type = play.play_type();
+            let outcome = play_type.and_then(|play_type| play_type.outcome());
+            let movements = play.movements().unwrap_or_default();
+
+            if play_type.is_some_and(|play_type| play_type.is_error()) {
+                teams[fielding].inning(inning.number).errors += 1;
+            }
+            if let (Some(batter), Some(outcome)) = (play.batter(), outcome) {
+                let line = teams[batting].batting_line(batter);
+                line.at_bats += u32::from(outcome.is_at_bat());
+                line.hits += u32::from(outcome.is_hit());
+                line.doubles += u32::from(outcome == Outcome::Double);
+                line.triples += u32::from(outcome == Outcome::Triple);
+                line.home_runs += u32::from(outcome == Outcome::HomeRun);
+                line.walks += u32::from(outcome.is_walk());
+                line.strikeouts += u32::from(outcome == Outcome::Strikeout);
+                if outcome.credits_runs_batted_in() {
+                    line.runs_batted_in += movements.iter().filter(|movement| movement.end_base == Base::Home && !movement.is_out).count() as u32;
+                }
+                teams[batting].inning(inning.number).hits += u32::from(outcome.is_hit());
+
+                if let Some(pitcher) = pitcher {
+                    let line = teams[fielding].pitching_line(pitcher);
+                    line.hits += u32::from(outcome.is_hit());
+                    line.home_runs += u32::from(outcome == Outcome::HomeRun);
+                    line.walks += u32::from(outcome.is_walk());
+                    line.strikeouts += u32::from(outcome == Outcome::Strikeout);
+                }
+            }
+
+            for movement in movements {
+                if movement.start_base == Base::Home
+                    && let Some(pitcher) = pitcher
+                {
+                    responsible_pitchers.insert(movement.runner.as_str(), pitcher);
+                }
+                if movement.is_out {
+                    if let Some(pitcher) = pitcher {
+                        teams[fielding].pitching_line(pitcher).outs += 1;
+                    }
+                    responsible_pitchers.remove(movement.runner.as_str());
+                } else if movement.end_base == Base::Home {
+                    teams[batting].batting_line(&movement.runner).runs += 1;
+                    *teams[batting].inning(inning.number).runs.get_or_insert(0) += 1;
+                    if let Some(pitcher) = responsible_pitchers.remove(movement.runner.as_str()).or(pitcher) {
+                        teams[fielding].pitching_line(pitcher).runs += 1;
+                    }
+                }
+            }
+        }
+
+        let innings = teams.iter().map(|team| team.innings.len()).max().unwrap_or(0) as u32;
+        for team in &mut teams {
+            team.inning(innings);
+        }
+        let [away, home] = teams;
+
+        Self { away, home }
+    }
+
+    fn write_linescore(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<10}", "")?;
+        for number in 1..=self.away.innings.len() {
+            write!(f, "{number:>3}")?;
+        }
+        writeln!(f, "  {:>3}{:>3}{:>3}", "R", "H", "E")?;
+        for (side, team) in [("away", &self.away), ("home", &self.home)] {
+            write!(f, "{:<10}", format!("{side} {}", team.team_id))?;
+            for inning in &team.innings {
+                write!(f, "{:>3}", inning.runs.map_or_else(|| "x".to_string(), |runs| runs.to_string()))?;
+            }
+            writeln!(f, "  {:>3}{:>3}{:>3}", team.runs(), team.hits(), team.errors())?;
+        }
+
+        Ok(())
+    }
+
+    fn write_batting(f: &mut fmt::Formatter<'_>, side: &str, team: &TeamBoxScore) -> fmt::Result {
+        let width = team.batting.iter().map(|line| line.name.chars().count()).max().unwrap_or(0).max(20);
+        writeln!(f, "{:<width$}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}", format!("{side} {} batting", team.team_id), "AB", "R", "H", "2B", "3B", "HR", "RBI", "BB", "K")?;
+        for line in &team.batting {
+            writeln!(
+                f,
+                "{:<width$}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}{:>4}",
+                line.name, line.at_bats, line.runs, line.hits, line.doubles, line.triples, line.home_runs, line.runs_batted_in, line.walks, line.strikeouts,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_pitching(f: &mut fmt::Formatter<'_>, side: &str, team: &TeamBoxScore) -> fmt::Result {
+        let width = team.pitching.iter().map(|line| line.name.chars().count()).max().unwrap_or(0).max(20);
+        writeln!(f, "{:<width$}{:>5}{:>4}{:>4}{:>4}{:>4}{:>4}", format!("{side} {} pitching", team.team_id), "IP", "H", "R", "BB", "K", "HR")?;
+        for line in &team.pitching {
+            writeln!(
+                f,
+                "{:<width$}{:>5}{:>4}{:>4}{:>4}{:>4}{:>4}",
+                line.name, line.innings_pitched(), line.hits, line.runs, line.walks, line.strikeouts, line.home_runs,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the box score as plain text: the linescore, then each team's batting lines, then each team's pitching lines.
+impl fmt::Display for BoxScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_linescore(f)?;
+        for (side, team) in [("away", &self.away), ("home", &self.home)] {
+            writeln!(f)?;
+            Self::write_batting(f, side, team)?;
+        }
+        for (side, team) in [("away", &self.away), ("home", &self.home)] {
+            writeln!(f)?;
+            Self::write_pitching(f, side, team)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Game {
+    /// The game's box score, as made by `BoxScore::new`.
+    pub fn box_score(&self) -> BoxScore {
+        BoxScore::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn box_score_adds_up() {
+        let box_score = Parser::new(false).parse_str(GAME).unwrap().remove(0).box_score();
+        let [away, home] = [&box_score.away, &box_score.home];
+
+        assert_eq!((away.runs(), away.hits(), away.errors()), (4, 7, 1));
+        assert_eq!((home.runs(), home.hits(), home.errors()), (9, 14, 1));
+        assert_eq!(home.innings.last().unwrap().runs, None);
+        assert_eq!(home.batting.iter().map(|line| line.runs_batted_in).sum::<u32>(), 9);
+        assert_eq!(home.batting[2], BattingLine {
+            name: "Luke Maile".to_string(),
+            at_bats: 3,
+            runs: 1,
+            hits: 1,
+            home_runs: 1,
+            runs_batted_in: 3,
+            strikeouts: 1,
+            ..Default::default()
+        });
+        // the home team's pitchers get all 27 outs and are charged with all of the away team's runs
+        assert_eq!(home.pitching.iter().map(|line| line.outs).sum::<u32>(), 27);
+        assert_eq!(home.pitching.iter().map(|line| line.runs).sum::<u32>(), 4);
+        assert_eq!(away.pitching.iter().map(|line| line.runs).sum::<u32>(), 9);
+        assert_eq!(home.pitching[0].innings_pitched(), "1.2");
+    }
+
+    #[test]
+    fn box_score_is_written_as_text() {
+        let text = Parser::new(false).parse_str(GAME).unwrap().remove(0).box_score().to_string();
+        let lines = text.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "            1  2  3  4  5  6  7  8  9    R  H  E");
+        assert_eq!(lines[1], "away 108    0  0  0  0  0  2  1  1  0    4  7  1");
+        assert_eq!(lines[2], "home 113    1  0  4  1  2  0  0  1  x    9 14  1");
+        assert!(lines.contains(&"Luke Maile                     3   1   1   0   0   1   3   0   1"));
+        assert!(lines.contains(&"Hunter Greene         1.2   1   0   2   4   0"));
+    }
+}
@@ -1,168 +1,292 @@
+#[cfg(feature = "python")]
 use pyo3::pyclass;
-use std::collections::HashMap;
-use strum::EnumIter;
-use serde::Deserialize;
-
-#[derive(Debug, PartialEq, Eq, EnumIter, Deserialize, Clone, Copy)]
-pub enum PlayType {
-    Groundout,
-    #[serde(rename = "Bunt Groundout")]
-    BuntGroundout,
-    Strikeout,
-    Lineout,
-    #[serde(rename = "Bunt Lineout")]
-    BuntLineout,
-    Flyout,
-    #[serde(rename = "Pop Out")]
-    PopOut,
-    #[serde(rename = "Bunt Pop Out")]
-    BuntPopOut,
-    Forceout,
-    #[serde(rename = "Fielders Choice Out")]
-    FieldersChoiceOut,
-    #[serde(rename = "Double Play")]
-    DoublePlay,
-    #[serde(rename = "Triple Play")]
-    TriplePlay,
-    #[serde(rename = "Runner Double Play")]
-    RunnerDoublePlay,
-    #[serde(rename = "Runner Triple Play")]
-    RunnerTriplePlay,
-    #[serde(rename = "Grounded Into Double Play")]
-    GroundedIntoDoublePlay,
-    #[serde(rename = "Strikeout Double Play")]
-    StrikeoutDoublePlay,
-    Pickoff,
-    #[serde(rename = "Pickoff Error")]
-    PickoffError,
-    #[serde(rename = "Caught Stealing")]
-    CaughtStealing,
-    #[serde(rename = "Pickoff Caught Stealing")]
-    PickoffCaughtStealing,
-    #[serde(rename = "Wild Pitch")]
-    WildPitch,
-    #[serde(rename = "Runner Out")]
-    RunnerOut,
-    #[serde(rename = "Field Out")]
-    FieldOut,
-    #[serde(rename = "Batter Out")]
-    BatterOut,
-    Balk,
-    #[serde(rename = "Passed Ball")]
-    PassedBall,
-    Error,
-    Single,
-    Double,
-    Triple,
-    #[serde(rename = "Home Run")]
-    HomeRun,
-    Walk,
-    #[serde(rename = "Intent Walk")]
-    IntentWalk,
-    #[serde(rename = "Hit By Pitch")]
-    HitByPitch,
-    #[serde(rename = "Fielders Choice")]
-    FieldersChoice,
-    #[serde(rename = "Catcher Interference")]
-    CatcherInterference,
-    #[serde(rename = "Stolen Base")]
-    StolenBase,
-    #[serde(rename = "Sac Fly")]
-    SacFly,
-    #[serde(rename = "Sac Fly Double Play")]
-    SacFlyDoublePlay,
-    #[serde(rename = "Sac Bunt")]
-    SacBunt,
-    #[serde(rename = "Sac Bunt Double Play")]
-    SacBuntDoublePlay,
-    #[serde(rename = "Field Error")]
-    FieldError,
-    #[serde(rename = "Game Advisory")]
-    GameAdvisory,
-    Ejection,
+use std::{collections::HashMap, fmt};
+use strum::{EnumIter, IntoEnumIterator};
+use serde::{Deserialize, Serialize};
+use super::profile::PlayKind;
+
+/// A field of a play besides its inning, in the order the fields appear on a play's lines.
+#[derive(Debug, PartialEq, Eq, Hash, EnumIter, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayField {
+    Base,
+    Batter,
+    Pitcher,
+    Catcher,
+    Fielders,
+    Runner,
+    ScoringRunner,
+    Movements,
 }
 
-impl ToString for PlayType {
-    fn to_string(&self) -> String {
+impl PlayField {
+    /// The field's key in a play's lines.
+    pub fn key(&self) -> &'static str {
         match self {
-            PlayType::Groundout => "Groundout",
-            PlayType::BuntGroundout => "Bunt Groundout",
-            PlayType::Strikeout => "Strikeout",
-            PlayType::Lineout => "Lineout",
-            PlayType::BuntLineout => "Bunt Lineout",
-            PlayType::Flyout => "Flyout",
-            PlayType::PopOut => "Pop Out",
-            PlayType::BuntPopOut => "Bunt Pop Out",
-            PlayType::Forceout => "Forceout",
-            PlayType::FieldersChoiceOut => "Fielders Choice Out",
-            PlayType::DoublePlay => "Double Play",
-            PlayType::TriplePlay => "Triple Play",
-            PlayType::RunnerDoublePlay => "Runner Double Play",
-            PlayType::RunnerTriplePlay => "Runner Triple Play",
-            PlayType::GroundedIntoDoublePlay => "Grounded Into Double Play",
-            PlayType::StrikeoutDoublePlay => "Strikeout Double Play",
-            PlayType::Pickoff => "Pickoff",
-            PlayType::PickoffError => "Pickoff Error",
-            PlayType::CaughtStealing => "Caught Stealing",
-            PlayType::PickoffCaughtStealing => "Pickoff Caught Stealing",
-            PlayType::WildPitch => "Wild Pitch",
-            PlayType::RunnerOut => "Runner Out",
-            PlayType::FieldOut => "Field Out",
-            PlayType::BatterOut => "Batter Out",
-            PlayType::Balk => "Balk",
-            PlayType::PassedBall => "Passed Ball",
-            PlayType::Error => "Error",
-            PlayType::Single => "Single",
-            PlayType::Double => "Double",
-            PlayType::Triple => "Triple",
-            PlayType::HomeRun => "Home Run",
-            PlayType::Walk => "Walk",
-            PlayType::IntentWalk => "Intent Walk",
-            PlayType::HitByPitch => "Hit By Pitch",
-            PlayType::FieldersChoice => "Fielders Choice",
-            PlayType::CatcherInterference => "Catcher Interference",
-            PlayType::StolenBase => "Stolen Base",
-            PlayType::SacFly => "Sac Fly",
-            PlayType::SacFlyDoublePlay => "Sac Fly Double Play",
-            PlayType::SacBunt => "Sac Bunt",
-            PlayType::SacBuntDoublePlay => "Sac Bunt Double Play",
-            PlayType::FieldError => "Field Error",
-            PlayType::GameAdvisory => "Game Advisory",
-            PlayType::Ejection => "Ejection",
-        }.to_string()
+            PlayField::Base => "base",
+            PlayField::Batter => "batter",
+            PlayField::Pitcher => "pitcher",
+            PlayField::Catcher => "catcher",
+            PlayField::Fielders => "fielders",
+            PlayField::Runner => "runner",
+            PlayField::ScoringRunner => "scoring_runner",
+            PlayField::Movements => "movements",
+        }
+    }
+
+    /// Whether the field belongs on a play's information line, rather than its movements line.
+    pub fn is_information(&self) -> bool {
+        *self != PlayField::Movements
+    }
+}
+
+/// The `PlayField` with the given key.
+macro_rules! play_field {
+    (base) => { PlayField::Base };
+    (batter) => { PlayField::Batter };
+    (pitcher) => { PlayField::Pitcher };
+    (catcher) => { PlayField::Catcher };
+    (fielders) => { PlayField::Fielders };
+    (runner) => { PlayField::Runner };
+    (scoring_runner) => { PlayField::ScoringRunner };
+    (movements) => { PlayField::Movements };
+}
+
+/// The type of the play field with the given key.
+macro_rules! play_field_type {
+    (base) => { Base };
+    (batter) => { String };
+    (pitcher) => { String };
+    (catcher) => { String };
+    (fielders) => { Vec<String> };
+    (runner) => { String };
+    (scoring_runner) => { String };
+    (movements) => { Vec<Movement> };
+}
+
+/// References to whichever fields a play has.
+#[derive(Default)]
+struct PlayFieldRefs<'a> {
+    base: Option<&'a Base>,
+    batter: Option<&'a String>,
+    pitcher: Option<&'a String>,
+    catcher: Option<&'a String>,
+    fielders: Option<&'a Vec<String>>,
+    runner: Option<&'a String>,
+    scoring_runner: Option<&'a String>,
+    movements: Option<&'a Vec<Movement>>,
+}
+
+/// Defines `PlayType`, `Play` and `PlayBuilder::build` from a table of play kinds, each with its variant, its name in
+/// the format, and its fields besides the inning, in the order they appear on the play's lines.
+macro_rules! play_kinds {
+    ($($variant:ident => $name:literal { $($field:ident),* },)*) => {
+        #[derive(Debug, PartialEq, Eq, Hash, EnumIter, Deserialize, Clone, Copy)]
+        pub enum PlayType {
+            $(
+                #[serde(rename = $name)]
+                $variant,
+            )*
+        }
+
+        impl PlayType {
+            /// The play type's name in the format.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(PlayType::$variant => $name,)*
+                }
+            }
+
+            /// The fields of a play of this type besides its inning.
+            pub fn fields(&self) -> &'static [PlayField] {
+                match self {
+                    $(PlayType::$variant => &[$(play_field!($field)),*],)*
+                }
+            }
+        }
+
+        #[allow(clippy::enum_variant_names)]
+        #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+        #[serde(tag = "type")]
+        #[cfg_attr(feature = "python", pyclass)]
+        pub enum Play {
+            $(
+                #[serde(rename = $name)]
+                $variant {
+                    inning: Inning,
+                    $($field: play_field_type!($field),)*
+                },
+            )*
+            /// A play of a type declared by a `FormatProfile` rather than built into the crate, with the fields its
+            /// type declares.
+            #[serde(skip)]
+            Custom {
+                inning: Inning,
+                name: String,
+                base: Option<Base>,
+                batter: Option<String>,
+                pitcher: Option<String>,
+                catcher: Option<String>,
+                fielders: Option<Vec<String>>,
+                runner: Option<String>,
+                scoring_runner: Option<String>,
+                movements: Option<Vec<Movement>>,
+            },
+        }
+
+        impl Play {
+            /// The built-in type of the play, or `None` for a `Custom` play.
+            pub fn play_type(&self) -> Option<PlayType> {
+                match self {
+                    $(Self::$variant { .. } => Some(PlayType::$variant),)*
+                    Self::Custom { .. } => None,
+                }
+            }
+
+            /// The play type's name in the format.
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Self::$variant { .. } => $name,)*
+                    Self::Custom { name, .. } => name,
+                }
+            }
+
+            pub fn inning(&self) -> &Inning {
+                match self {
+                    $(Self::$variant { inning, .. } => inning,)*
+                    Self::Custom { inning, .. } => inning,
+                }
+            }
+
+            fn field_refs(&self) -> PlayFieldRefs<'_> {
+                match self {
+                    $(Self::$variant { $($field,)* .. } => PlayFieldRefs {
+                        $($field: Some($field),)*
+                        ..PlayFieldRefs::default()
+                    },)*
+                    Self::Custom { base, batter, pitcher, catcher, fielders, runner, scoring_runner, movements, .. } => PlayFieldRefs {
+                        base: base.as_ref(),
+                        batter: batter.as_ref(),
+                        pitcher: pitcher.as_ref(),
+                        catcher: catcher.as_ref(),
+                        fielders: fielders.as_ref(),
+                        runner: runner.as_ref(),
+                        scoring_runner: scoring_runner.as_ref(),
+                        movements: movements.as_ref(),
+                    },
+                }
+            }
+        }
+
+        impl PlayBuilder {
+            /// Builds a play of a built-in type, once `build` has checked that the builder has exactly its fields.
+            fn build_built_in(&self, play_type: PlayType) -> Play {
+                match play_type {
+                    $(PlayType::$variant => Play::$variant {
+                        inning: self.inning.expect(concat!("inning should be set for building a ", stringify!($variant))),
+                        $($field: self.$field.clone().expect(concat!(stringify!($field), " should be set for building a ", stringify!($variant))),)*
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+play_kinds! {
+    Groundout => "Groundout" { batter, pitcher, fielders, movements },
+    BuntGroundout => "Bunt Groundout" { batter, pitcher, fielders, movements },
+    Strikeout => "Strikeout" { batter, pitcher, movements },
+    Lineout => "Lineout" { batter, pitcher, fielders, movements },
+    BuntLineout => "Bunt Lineout" { batter, pitcher, fielders, movements },
+    Flyout => "Flyout" { batter, pitcher, fielders, movements },
+    PopOut => "Pop Out" { batter, pitcher, fielders, movements },
+    BuntPopOut => "Bunt Pop Out" { batter, pitcher, fielders, movements },
+    Forceout => "Forceout" { batter, pitcher, fielders, movements },
+    FieldersChoiceOut => "Fielders Choice Out" { batter, pitcher, fielders, scoring_runner, movements },
+    DoublePlay => "Double Play" { batter, pitcher, fielders, movements },
+    TriplePlay => "Triple Play" { batter, pitcher, fielders, movements },
+    RunnerDoublePlay => "Runner Double Play" { batter, pitcher, fielders, movements },
+    RunnerTriplePlay => "Runner Triple Play" { batter, pitcher, fielders, movements },
+    GroundedIntoDoublePlay => "Grounded Into Double Play" { batter, pitcher, fielders, movements },
+    StrikeoutDoublePlay => "Strikeout Double Play" { batter, pitcher, fielders, movements },
+    Pickoff => "Pickoff" { base, fielders, runner, movements },
+    PickoffError => "Pickoff Error" { base, fielders, runner, movements },
+    CaughtStealing => "Caught Stealing" { base, fielders, runner, movements },
+    PickoffCaughtStealing => "Pickoff Caught Stealing" { base, fielders, runner, movements },
+    WildPitch => "Wild Pitch" { pitcher, runner, movements },
+    RunnerOut => "Runner Out" { fielders, runner, movements },
+    FieldOut => "Field Out" { fielders, runner, movements },
+    BatterOut => "Batter Out" { batter, catcher, movements },
+    Balk => "Balk" { pitcher, movements },
+    PassedBall => "Passed Ball" { pitcher, catcher, movements },
+    Error => "Error" { pitcher, catcher, movements },
+    Single => "Single" { batter, pitcher, movements },
+    Double => "Double" { batter, pitcher, movements },
+    Triple => "Triple" { batter, pitcher, movements },
+    HomeRun => "Home Run" { batter, pitcher, movements },
+    Walk => "Walk" { batter, pitcher, movements },
+    IntentWalk => "Intent Walk" { batter, pitcher, movements },
+    HitByPitch => "Hit By Pitch" { batter, pitcher, movements },
+    FieldersChoice => "Fielders Choice" { batter, pitcher, fielders, movements },
+    CatcherInterference => "Catcher Interference" { batter, pitcher, fielders, movements },
+    StolenBase => "Stolen Base" { base, runner, movements },
+    SacFly => "Sac Fly" { batter, pitcher, fielders, scoring_runner, movements },
+    SacFlyDoublePlay => "Sac Fly Double Play" { batter, pitcher, fielders, scoring_runner, movements },
+    SacBunt => "Sac Bunt" { batter, pitcher, fielders, runner, movements },
+    SacBuntDoublePlay => "Sac Bunt Double Play" { batter, pitcher, fielders, runner, movements },
+    FieldError => "Field Error" { batter, pitcher, fielders, movements },
+    GameAdvisory => "Game Advisory" {},
+    Ejection => "Ejection" { movements },
+}
+
+impl PlayType {
+    /// Whether a play of this type has an information line.
+    pub fn has_information_line(&self) -> bool {
+        self.fields().iter().any(PlayField::is_information)
+    }
+
+    /// Whether a play of this type has a movements line.
+    pub fn has_movements_line(&self) -> bool {
+        self.fields().contains(&PlayField::Movements)
+    }
+}
+
+impl fmt::Display for PlayType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename = "weather")]
-#[pyclass(get_all)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Weather {
     pub condition: String,
-    pub temperature: u32,
+    pub temperature: i32,
     pub wind_speed: u32,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename = "player")]
-#[pyclass(get_all)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Player {
     pub position: String,
     pub name: String,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
-#[pyclass(get_all)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Team {
     pub id: u32,
     pub players: Vec<Player>,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
-#[pyclass(get_all)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Context {
     pub game_pk: u32,
     pub date: String,
@@ -172,8 +296,8 @@ pub struct Context {
     pub away_team: Team,
 }
 
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[pyclass(get_all)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Inning {
     pub number: u32,
     pub top: bool,
@@ -195,8 +319,8 @@ impl Inning {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
-#[pyclass(get_all)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub enum Base {
     #[serde(rename = "home")]
     Home,
@@ -208,14 +332,14 @@ pub enum Base {
     Third,
 }
 
-impl ToString for Base {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
             Base::Home => "home",
             Base::First => "1",
             Base::Second => "2",
             Base::Third => "3",
-        }.to_string()
+        })
     }
 }
 
@@ -231,8 +355,8 @@ impl Base {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
-#[pyclass(get_all)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Movement {
     pub runner: String,
     pub start_base: Base,
@@ -240,461 +364,89 @@ pub struct Movement {
     pub is_out: bool,
 }
 
-#[allow(clippy::enum_variant_names, dead_code)]
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
-#[serde(tag = "type")]
-#[pyclass]
-pub enum Play {
-    Groundout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Bunt Groundout")]
-    BuntGroundout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    Strikeout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    Lineout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Bunt Lineout")]
-    BuntLineout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    Flyout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Pop Out")]
-    PopOut {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Bunt Pop Out")]
-    BuntPopOut {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    Forceout {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Fielders Choice Out")]
-    FieldersChoiceOut {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        scoring_runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Double Play")]
-    DoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Triple Play")]
-    TriplePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Runner Double Play")]
-    RunnerDoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Runner Triple Play")]
-    RunnerTriplePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Grounded Into Double Play")]
-    GroundedIntoDoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Strikeout Double Play")]
-    StrikeoutDoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    Pickoff {
-        inning: Inning,
-        base: Base,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Pickoff Error")]
-    PickoffError {
-        inning: Inning,
-        base: Base,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Caught Stealing")]
-    CaughtStealing {
-        inning: Inning,
-        base: Base,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Pickoff Caught Stealing")]
-    PickoffCaughtStealing {
-        inning: Inning,
-        base: Base,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Wild Pitch")]
-    WildPitch {
-        inning: Inning,
-        pitcher: String,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Runner Out")]
-    RunnerOut {
-        inning: Inning,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Field Out")]
-    FieldOut {
-        inning: Inning,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Batter Out")]
-    BatterOut {
-        inning: Inning,
-        batter: String,
-        catcher: String,
-        movements: Vec<Movement>,
-    },
-    Balk {
-        inning: Inning,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Passed Ball")]
-    PassedBall {
-        inning: Inning,
-        pitcher: String,
-        catcher: String,
-        movements: Vec<Movement>,
-    },
-    Error {
-        inning: Inning,
-        pitcher: String,
-        catcher: String,
-        movements: Vec<Movement>,
-    },
-    Single {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    Double {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    Triple {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Home Run")]
-    HomeRun {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    Walk {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Intent Walk")]
-    IntentWalk {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Hit By Pitch")]
-    HitByPitch {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Fielders Choice")]
-    FieldersChoice {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Catcher Interference")]
-    CatcherInterference {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Stolen Base")]
-    StolenBase {
-        inning: Inning,
-        base: Base,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Sac Fly")]
-    SacFly {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        scoring_runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Sac Fly Double Play")]
-    SacFlyDoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        scoring_runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Sac Bunt")]
-    SacBunt {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Sac Bunt Double Play")]
-    SacBuntDoublePlay {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        runner: String,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Field Error")]
-    FieldError {
-        inning: Inning,
-        batter: String,
-        pitcher: String,
-        fielders: Vec<String>,
-        movements: Vec<Movement>,
-    },
-    #[serde(rename = "Game Advisory")]
-    GameAdvisory {
-        inning: Inning,
-    },
-    Ejection {
-        inning: Inning,
-        movements: Vec<Movement>,
-    },
-}
-
 impl Play {
-    pub fn get_movements(&self) -> Option<&Vec<Movement>> {
-        match self {
-            Self::GameAdvisory { .. } => None,
-            Self::Groundout { movements, .. }
-                | Self::BuntGroundout { movements, .. }
-                | Self::Strikeout { movements, .. }
-                | Self::Lineout { movements, .. }
-                | Self::BuntLineout { movements, .. }
-                | Self::Flyout { movements, .. }
-                | Self::PopOut { movements, .. }
-                | Self::BuntPopOut { movements, .. }
-                | Self::Forceout { movements, .. }
-                | Self::FieldersChoiceOut { movements, .. }
-                | Self::DoublePlay { movements, .. }
-                | Self::TriplePlay { movements, .. }
-                | Self::RunnerDoublePlay { movements, .. }
-                | Self::RunnerTriplePlay { movements, .. }
-                | Self::GroundedIntoDoublePlay { movements, .. }
-                | Self::StrikeoutDoublePlay { movements, .. }
-                | Self::Pickoff { movements, .. }
-                | Self::PickoffError { movements, .. }
-                | Self::CaughtStealing { movements, .. }
-                | Self::PickoffCaughtStealing { movements, .. }
-                | Self::WildPitch { movements, .. }
-                | Self::RunnerOut { movements, .. }
-                | Self::FieldOut { movements, .. }
-                | Self::BatterOut { movements, .. }
-                | Self::Balk { movements, .. }
-                | Self::PassedBall { movements, .. }
-                | Self::Error { movements, .. }
-                | Self::Single { movements, .. }
-                | Self::Double { movements, .. }
-                | Self::Triple { movements, .. }
-                | Self::HomeRun { movements, .. }
-                | Self::Walk { movements, .. }
-                | Self::IntentWalk { movements, .. }
-                | Self::HitByPitch { movements, .. }
-                | Self::FieldersChoice { movements, .. }
-                | Self::CatcherInterference { movements, .. }
-                | Self::StolenBase { movements, .. }
-                | Self::SacFly { movements, .. }
-                | Self::SacFlyDoublePlay { movements, .. }
-                | Self::SacBunt { movements, .. }
-                | Self::SacBuntDoublePlay { movements, .. }
-                | Self::FieldError { movements, .. }
-                | Self::Ejection { movements, .. } => Some(movements),
-        }
+    /// The base the play happened at, if its type has one.
+    pub fn base(&self) -> Option<Base> {
+        self.field_refs().base.copied()
+    }
+
+    /// The batter at the plate, if the play ends a plate appearance.
+    pub fn batter(&self) -> Option<&str> {
+        self.field_refs().batter.map(String::as_str)
+    }
+
+    /// The pitcher on the mound, if the play's type records one.
+    pub fn pitcher(&self) -> Option<&str> {
+        self.field_refs().pitcher.map(String::as_str)
+    }
+
+    /// The catcher behind the plate, if the play's type records one, such as a passed ball.
+    pub fn catcher(&self) -> Option<&str> {
+        self.field_refs().catcher.map(String::as_str)
+    }
+
+    /// The fielders credited on the play, if its type records them.
+    pub fn fielders(&self) -> Option<&[String]> {
+        self.field_refs().fielders.map(Vec::as_slice)
+    }
+
+    /// The runner the play is about, such as the one caught stealing, if its type has one.
+    pub fn runner(&self) -> Option<&str> {
+        self.field_refs().runner.map(String::as_str)
+    }
+
+    /// The runner who scored on the play, if its type records one, such as a sac fly.
+    pub fn scoring_runner(&self) -> Option<&str> {
+        self.field_refs().scoring_runner.map(String::as_str)
+    }
+
+    /// The runners' movements, or `None` for a play type without a movements line.
+    pub fn movements(&self) -> Option<&[Movement]> {
+        self.field_refs().movements.map(Vec::as_slice)
     }
 
+    #[deprecated(note = "use `inning` instead")]
     pub fn get_inning(&self) -> &Inning {
-        match self {
-            Self::Groundout { inning, .. }
-            | Self::BuntGroundout { inning, .. }
-            | Self::Strikeout { inning, .. }
-            | Self::Lineout { inning, .. }
-            | Self::BuntLineout { inning, .. }
-            | Self::Flyout { inning, .. }
-            | Self::PopOut { inning, .. }
-            | Self::BuntPopOut { inning, .. }
-            | Self::Forceout { inning, .. }
-            | Self::FieldersChoiceOut { inning, .. }
-            | Self::DoublePlay { inning, .. }
-            | Self::TriplePlay { inning, .. }
-            | Self::RunnerDoublePlay { inning, .. }
-            | Self::RunnerTriplePlay { inning, .. }
-            | Self::GroundedIntoDoublePlay { inning, .. }
-            | Self::StrikeoutDoublePlay { inning, .. }
-            | Self::Pickoff { inning, .. }
-            | Self::PickoffError { inning, .. }
-            | Self::CaughtStealing { inning, .. }
-            | Self::PickoffCaughtStealing { inning, .. }
-            | Self::WildPitch { inning, .. }
-            | Self::RunnerOut { inning, .. }
-            | Self::FieldOut { inning, .. }
-            | Self::BatterOut { inning, .. }
-            | Self::Balk { inning, .. }
-            | Self::PassedBall { inning, .. }
-            | Self::Error { inning, .. }
-            | Self::Single { inning, .. }
-            | Self::Double { inning, .. }
-            | Self::Triple { inning, .. }
-            | Self::HomeRun { inning, .. }
-            | Self::Walk { inning, .. }
-            | Self::IntentWalk { inning, .. }
-            | Self::HitByPitch { inning, .. }
-            | Self::FieldersChoice { inning, .. }
-            | Self::CatcherInterference { inning, .. }
-            | Self::StolenBase { inning, .. }
-            | Self::SacFly { inning, .. }
-            | Self::SacFlyDoublePlay { inning, .. }
-            | Self::SacBunt { inning, .. }
-            | Self::SacBuntDoublePlay { inning, .. }
-            | Self::FieldError { inning, .. }
-            | Self::GameAdvisory { inning, .. }
-            | Self::Ejection { inning, .. } => inning,
-        }
+        self.inning()
+    }
+
+    #[deprecated(note = "use `movements` instead")]
+    pub fn get_movements(&self) -> Option<&Vec<Movement>> {
+        self.field_refs().movements
     }
 
+    #[deprecated(note = "use `runner` instead")]
     pub fn get_runner(&self) -> Option<&String> {
-        match self {
-            Self::Pickoff { runner, .. }
-            | Self::PickoffError { runner, .. }
-            | Self::CaughtStealing { runner, .. }
-            | Self::PickoffCaughtStealing { runner, .. }
-            | Self::WildPitch { runner, .. }
-            | Self::RunnerOut { runner, .. }
-            | Self::FieldOut { runner, .. }
-            | Self::StolenBase { runner, .. }
-            | Self::SacBunt { runner, .. }
-            | Self::SacBuntDoublePlay { runner, .. } => Some(runner),
-            _ => None,
-        }
+        self.field_refs().runner
     }
 
+    #[deprecated(note = "use `scoring_runner` instead")]
     pub fn get_scoring_runner(&self) -> Option<&String> {
-        match self {
-            Self::FieldersChoiceOut { scoring_runner, .. }
-            | Self::SacFly { scoring_runner, .. }
-            | Self::SacFlyDoublePlay { scoring_runner, .. } => Some(scoring_runner),
-            _ => None,
-        }
+        self.field_refs().scoring_runner
+    }
+
+    /// The value of `field` as JSON, or `None` if the play does not have it.
+    pub(crate) fn field_json(&self, field: PlayField) -> Option<serde_json::Value> {
+        let refs = self.field_refs();
+        let value = match field {
+            PlayField::Base => serde_json::to_value(refs.base?),
+            PlayField::Batter => serde_json::to_value(refs.batter?),
+            PlayField::Pitcher => serde_json::to_value(refs.pitcher?),
+            PlayField::Catcher => serde_json::to_value(refs.catcher?),
+            PlayField::Fielders => serde_json::to_value(refs.fielders?),
+            PlayField::Runner => serde_json::to_value(refs.runner?),
+            PlayField::ScoringRunner => serde_json::to_value(refs.scoring_runner?),
+            PlayField::Movements => serde_json::to_value(refs.movements?),
+        };
+        Some(value.expect("play fields serialize to JSON"))
     }
 }
 
 pub struct PlayBuilder {
     pub inning: Option<Inning>,
     pub play_type: Option<PlayType>,
+    /// The play's type as declared by the parser's profile, which may not be built in.
+    pub play_kind: Option<PlayKind>,
     pub base: Option<Base>,
     pub batter: Option<String>,
     pub pitcher: Option<String>,
@@ -705,11 +457,18 @@ pub struct PlayBuilder {
     pub movements: Option<Vec<Movement>>,
 }
 
+impl Default for PlayBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PlayBuilder {
     pub fn new() -> Self {
         Self {
             inning: None,
             play_type: None,
+            play_kind: None,
             base: None,
             batter: None,
             pitcher: None,
@@ -727,6 +486,14 @@ impl PlayBuilder {
 
     pub fn set_play_type(&mut self, play_type: PlayType) {
         self.play_type = Some(play_type);
+        self.play_kind = Some(PlayKind::from(play_type));
+    }
+
+    /// Sets the play's type to one declared by a profile. Plays of a built-in type are built as that type's variant,
+    /// and plays of any other type as `Play::Custom`.
+    pub fn set_play_kind(&mut self, play_kind: PlayKind) {
+        self.play_type = play_kind.play_type();
+        self.play_kind = Some(play_kind);
     }
 
     pub fn set_base(&mut self, base: Base) {
@@ -761,311 +528,107 @@ impl PlayBuilder {
         self.movements = Some(movements);
     }
 
-    pub fn build(&self) -> Play {
-        match &self.play_type {
-            Some(play_type) => match play_type {
-                PlayType::Groundout => Play::Groundout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Groundout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Groundout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Groundout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a Groundout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Groundout").clone(),
-                },
-                PlayType::BuntGroundout => Play::BuntGroundout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a BuntGroundout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a BuntGroundout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a BuntGroundout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a BuntGroundout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a BuntGroundout").clone(),
-                },
-                PlayType::Strikeout => Play::Strikeout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Strikeout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Strikeout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Strikeout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Strikeout").clone(),
-                },
-                PlayType::Lineout => Play::Lineout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Lineout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Lineout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Lineout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a Lineout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Lineout").clone(),
-                },
-                PlayType::BuntLineout => Play::BuntLineout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a BuntLineout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a BuntLineout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a BuntLineout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a BuntLineout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a BuntLineout").clone(),
-                },
-                PlayType::Flyout => Play::Flyout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Flyout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Flyout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Flyout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a Flyout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Flyout").clone(),
-                },
-                PlayType::PopOut => Play::PopOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a PopOut"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a PopOut").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a PopOut").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a PopOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a PopOut").clone(),
-                },
-                PlayType::BuntPopOut => Play::BuntPopOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a BuntPopOut"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a BuntPopOut").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a BuntPopOut").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a BuntPopOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a BuntPopOut").clone(),
-                },
-                PlayType::Forceout => Play::Forceout {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Forceout"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Forceout").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Forceout").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a Forceout").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Forceout").clone(),
-                },
-                PlayType::FieldersChoiceOut => Play::FieldersChoiceOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a FieldersChoiceOut"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a FieldersChoiceOut").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a FieldersChoiceOut").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a FieldersChoiceOut").clone(),
-                    scoring_runner: self.scoring_runner.as_ref().expect("scoring_runner should be set for building a FieldersChoiceOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a FieldersChoiceOut").clone(),
-                },
-                PlayType::DoublePlay => Play::DoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a DoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a DoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a DoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a DoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a DoublePlay").clone(),
-                },
-                PlayType::TriplePlay => Play::TriplePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a TriplePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a TriplePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a TriplePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a TriplePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a TriplePlay").clone(),
-                },
-                PlayType::RunnerDoublePlay => Play::RunnerDoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a RunnerDoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a RunnerDoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a RunnerDoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a RunnerDoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a RunnerDoublePlay").clone(),
-                },
-                PlayType::RunnerTriplePlay => Play::RunnerTriplePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a RunnerTriplePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a RunnerTriplePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a RunnerTriplePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a RunnerTriplePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a RunnerTriplePlay").clone(),
-                },
-                PlayType::GroundedIntoDoublePlay => Play::GroundedIntoDoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a GroundedIntoDoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a GroundedIntoDoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a GroundedIntoDoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a GroundedIntoDoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a GroundedIntoDoublePlay").clone(),
-                },
-                PlayType::StrikeoutDoublePlay => Play::StrikeoutDoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a StrikeoutDoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a StrikeoutDoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a StrikeoutDoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a StrikeoutDoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a StrikeoutDoublePlay").clone(),
-                },
-                PlayType::Pickoff => Play::Pickoff {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Pickoff"),
-                    base: self.base.as_ref().expect("base should be set for building a Pickoff").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a Pickoff").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a Pickoff").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Pickoff").clone(),
-                },
-                PlayType::PickoffError => Play::PickoffError {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a PickoffError"),
-                    base: self.base.as_ref().expect("base should be set for building a PickoffError").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a PickoffError").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a PickoffError").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a PickoffError").clone(),
-                },
-                PlayType::CaughtStealing => Play::CaughtStealing {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a CaughtStealing"),
-                    base: self.base.as_ref().expect("base should be set for building a CaughtStealing").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a CaughtStealing").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a CaughtStealing").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a CaughtStealing").clone(),
-                },
-                PlayType::PickoffCaughtStealing => Play::PickoffCaughtStealing {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a PickoffCaughtStealing"),
-                    base: self.base.as_ref().expect("base should be set for building a PickoffCaughtStealing").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a PickoffCaughtStealing").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a PickoffCaughtStealing").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a PickoffCaughtStealing").clone(),
-                },
-                PlayType::WildPitch => Play::WildPitch {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a WildPitch"),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a WildPitch").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a WildPitch").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a WildPitch").clone(),
-                },
-                PlayType::RunnerOut => Play::RunnerOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a RunnerOut"),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a RunnerOut").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a RunnerOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a RunnerOut").clone(),
-                },
-                PlayType::FieldOut => Play::FieldOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a FieldOut"),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a FieldOut").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a FieldOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a FieldOut").clone(),
-                },
-                PlayType::BatterOut => Play::BatterOut {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a BatterOut"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a BatterOut").clone(),
-                    catcher: self.catcher.as_ref().expect("catcher should be set for building a BatterOut").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a BatterOut").clone(),
-                },
-                PlayType::Balk => Play::Balk {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Balk"),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Balk").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Balk").clone(),
-                },
-                PlayType::PassedBall => Play::PassedBall {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a PassedBall"),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a PassedBall").clone(),
-                    catcher: self.catcher.as_ref().expect("catcher should be set for building a PassedBall").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a PassedBall").clone(),
-                },
-                PlayType::Error => Play::Error {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Error"),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Error").clone(),
-                    catcher: self.catcher.as_ref().expect("catcher should be set for building a Error").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Error").clone(),
-                },
-                PlayType::Single => Play::Single {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Single"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Single").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Single").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Single").clone(),
-                },
-                PlayType::Double => Play::Double {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Double"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Double").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Double").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Double").clone(),
-                },
-                PlayType::Triple => Play::Triple {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Triple"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Triple").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Triple").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Triple").clone(),
-                },
-                PlayType::HomeRun => Play::HomeRun {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a HomeRun"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a HomeRun").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a HomeRun").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a HomeRun").clone(),
-                },
-                PlayType::Walk => Play::Walk {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Walk"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a Walk").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a Walk").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Walk").clone(),
-                },
-                PlayType::IntentWalk => Play::IntentWalk {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a IntentWalk"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a IntentWalk").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a IntentWalk").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a IntentWalk").clone(),
-                },
-                PlayType::HitByPitch => Play::HitByPitch {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a HitByPitch"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a HitByPitch").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a HitByPitch").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a HitByPitch").clone(),
-                },
-                PlayType::FieldersChoice => Play::FieldersChoice {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a FieldersChoice"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a FieldersChoice").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a FieldersChoice").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a FieldersChoice").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a FieldersChoice").clone(),
-                },
-                PlayType::CatcherInterference => Play::CatcherInterference {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a CatcherInterference"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a CatcherInterference").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a CatcherInterference").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a CatcherInterference").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a CatcherInterference").clone(),
-                },
-                PlayType::StolenBase => Play::StolenBase {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a StolenBase"),
-                    base: self.base.as_ref().expect("base should be set for building a StolenBase").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a StolenBase").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a StolenBase").clone(),
-                },
-                PlayType::SacFly => Play::SacFly {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a SacFly"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a SacFly").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a SacFly").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a SacFly").clone(),
-                    scoring_runner: self.scoring_runner.as_ref().expect("scoring_runner should be set for building a SacFly").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a SacFly").clone(),
-                },
-                PlayType::SacFlyDoublePlay => Play::SacFlyDoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a SacFlyDoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a SacFlyDoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a SacFlyDoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a SacFlyDoublePlay").clone(),
-                    scoring_runner: self.scoring_runner.as_ref().expect("scoring_runner should be set for building a SacFlyDoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a SacFlyDoublePlay").clone(),
-                },
-                PlayType::SacBunt => Play::SacBunt {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a SacBunt"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a SacBunt").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a SacBunt").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a SacBunt").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a SacBunt").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a SacBunt").clone(),
-                },
-                PlayType::SacBuntDoublePlay => Play::SacBuntDoublePlay {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a SacBuntDoublePlay"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a SacBuntDoublePlay").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a SacBuntDoublePlay").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a SacBuntDoublePlay").clone(),
-                    runner: self.runner.as_ref().expect("runner should be set for building a SacBuntDoublePlay").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a SacBuntDoublePlay").clone(),
-                },
-                PlayType::FieldError => Play::FieldError {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a FieldError"),
-                    batter: self.batter.as_ref().expect("batter should be set for building a FieldError").clone(),
-                    pitcher: self.pitcher.as_ref().expect("pitcher should be set for building a FieldError").clone(),
-                    fielders: self.fielders.as_ref().expect("fielders should be set for building a FieldError").clone(),
-                    movements: self.movements.as_ref().expect("movements should be set for building a FieldError").clone(),
-                },
-                PlayType::GameAdvisory => Play::GameAdvisory {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a GameAdvisory"),
-                },
-                PlayType::Ejection => Play::Ejection {
-                    inning: *self.inning.as_ref().expect("inning should be set for building a Ejection"),
-                    movements: self.movements.as_ref().expect("movements should be set for building a Ejection").clone(),
-                },
-            }
-            None => panic!("Play type not set"),
+    /// Whether `field` has been set.
+    fn has(&self, field: PlayField) -> bool {
+        match field {
+            PlayField::Base => self.base.is_some(),
+            PlayField::Batter => self.batter.is_some(),
+            PlayField::Pitcher => self.pitcher.is_some(),
+            PlayField::Catcher => self.catcher.is_some(),
+            PlayField::Fielders => self.fielders.is_some(),
+            PlayField::Runner => self.runner.is_some(),
+            PlayField::ScoringRunner => self.scoring_runner.is_some(),
+            PlayField::Movements => self.movements.is_some(),
+        }
+    }
+
+    /// Builds the play, or returns every field that its type needs but is not set, and every field that is set but does
+    /// not belong to its type.
+    pub fn build(&self) -> Result<Play, MissingFields> {
+        let Some(play_kind) = &self.play_kind else {
+            return Err(MissingFields {
+                play_type: None,
+                missing: [self.inning.is_none().then_some("inning"), Some("type")].into_iter().flatten().collect(),
+                unexpected: Vec::new(),
+            });
+        };
+
+        let belongs = |field: &PlayField| play_kind.information.contains(field) || (*field == PlayField::Movements && play_kind.movements);
+        let missing = self.inning.is_none().then_some("inning").into_iter()
+            .chain(PlayField::iter().filter(|field| belongs(field) && !self.has(*field)).map(|field| field.key()))
+            .collect::<Vec<_>>();
+        let unexpected = PlayField::iter().filter(|field| !belongs(field) && self.has(*field)).map(|field| field.key()).collect::<Vec<_>>();
+        if !missing.is_empty() || !unexpected.is_empty() {
+            return Err(MissingFields { play_type: Some(play_kind.name.clone()), missing, unexpected });
+        }
+
+        Ok(match self.play_type {
+            Some(play_type) => self.build_built_in(play_type),
+            None => Play::Custom {
+                inning: self.inning.expect("the inning was checked above"),
+                name: play_kind.name.clone(),
+                base: self.base,
+                batter: self.batter.clone(),
+                pitcher: self.pitcher.clone(),
+                catcher: self.catcher.clone(),
+                fielders: self.fielders.clone(),
+                runner: self.runner.clone(),
+                scoring_runner: self.scoring_runner.clone(),
+                movements: self.movements.clone(),
+            },
+        })
+    }
+}
+
+/// The fields that kept a `PlayBuilder` from building a play: those its play type needs but are not set, and those that
+/// are set but do not belong to its play type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFields {
+    /// The name of the play type, or `None` if it was not set.
+    pub play_type: Option<String>,
+    pub missing: Vec<&'static str>,
+    pub unexpected: Vec<&'static str>,
+}
+
+impl fmt::Display for MissingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut problems = Vec::new();
+        if !self.missing.is_empty() {
+            problems.push(format!("missing {}", self.missing.join(", ")));
+        }
+        if !self.unexpected.is_empty() {
+            problems.push(format!("unexpected {}", self.unexpected.join(", ")));
         }
+
+        write!(f, "{}: {}", self.play_type.as_deref().unwrap_or("play"), problems.join("; "))
     }
 }
 
 #[derive(Debug)]
-#[pyclass(get_all)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Game {
     pub context: Context,
     pub plays: Vec<Play>,
 }
 
+/// The runners on first, second and third base after `plays`, starting with the bases empty, as the parser tracks them:
+/// the bases are cleared whenever a new half inning starts.
+pub fn bases_after_plays(plays: &[Play]) -> [Option<String>; 3] {
+    let mut game_builder = GameBuilder::new();
+    let mut inning = None;
+    for play in plays {
+        if inning.is_some_and(|inning| inning != *play.inning()) {
+            game_builder.clear_runner_positions();
+        }
+        inning = Some(*play.inning());
+        if let Some(movements) = play.movements() {
+            game_builder.process_movements(movements);
+        }
+    }
+
+    [Base::First, Base::Second, Base::Third].map(|base| game_builder.runner_positions[&base].clone())
+}
+
 pub struct GameBuilder {
     pub context: Option<Context>,
     pub plays: Vec<Play>,
@@ -1074,6 +637,12 @@ pub struct GameBuilder {
     pub runner_positions: HashMap<Base, Option<String>>,
 }
 
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameBuilder {
     pub fn new() -> Self {
         Self {
@@ -1097,7 +666,7 @@ impl GameBuilder {
     }
 
     pub fn add_play(&mut self, play: Play) {
-        if let Some(movements) = play.get_movements() {
+        if let Some(movements) = play.movements() {
             self.process_movements(movements);
         }
         self.plays.push(play);
@@ -1111,7 +680,7 @@ impl GameBuilder {
         ]);
     }
 
-    pub fn process_movements(&mut self, movements: &Vec<Movement>) {
+    pub fn process_movements(&mut self, movements: &[Movement]) {
         // Make a temporary copy to track changes during this processing
         let mut runner_positions = self.runner_positions.clone();
 
@@ -1136,10 +705,10 @@ impl GameBuilder {
                 // Clear the previous base if it was the same runner
                 if movement.start_base != Base::Home {
                     // Only clear the base if the runner there matches the current runner
-                    if let Some(Some(runner)) = runner_positions.get(&movement.start_base) {
-                        if *runner == movement.runner {
-                            runner_positions.insert(movement.start_base, None);
-                        }
+                    if let Some(Some(runner)) = runner_positions.get(&movement.start_base)
+                        && *runner == movement.runner
+                    {
+                        runner_positions.insert(movement.start_base, None);
                     }
                 }
             }
@@ -1150,47 +719,31 @@ impl GameBuilder {
     }
 
     pub fn home_team_player_names(&self) -> Option<Vec<String>> {
-        if let Some(context) = &self.context {
-            Some(context.home_team.players.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
-        } else {
-            None
-        }
+        self.context.as_ref().map(|context| context.home_team.players.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
     }
 
     pub fn away_team_player_names(&self) -> Option<Vec<String>> {
-        if let Some(context) = &self.context {
-            Some(context.away_team.players.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
-        } else {
-            None
-        }
+        self.context.as_ref().map(|context| context.away_team.players.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
     }
 
     pub fn home_team_pinch_runner_names(&self) -> Option<Vec<String>> {
-        if let Some(context) = &self.context {
-            Some(context.home_team.players.iter().filter_map(|p| {
-                if p.position == "PINCH_RUNNER" {
-                    Some(p.name.clone())
-                } else {
-                    None
-                }
-            }).collect::<Vec<_>>())
-        } else {
-            None
-        }
+        self.context.as_ref().map(|context| context.home_team.players.iter().filter_map(|p| {
+            if p.position == "PINCH_RUNNER" {
+                Some(p.name.clone())
+            } else {
+                None
+            }
+        }).collect::<Vec<_>>())
     }
 
     pub fn away_team_pinch_runner_names(&self) -> Option<Vec<String>> {
-        if let Some(context) = &self.context {
-            Some(context.away_team.players.iter().filter_map(|p| {
-                if p.position == "PINCH_RUNNER" {
-                    Some(p.name.clone())
-                } else {
-                    None
-                }
-            }).collect::<Vec<_>>())
-        } else {
-            None
-        }
+        self.context.as_ref().map(|context| context.away_team.players.iter().filter_map(|p| {
+            if p.position == "PINCH_RUNNER" {
+                Some(p.name.clone())
+            } else {
+                None
+            }
+        }).collect::<Vec<_>>())
     }
 
     pub fn build(&self) -> Result<Game, String> {
@@ -1212,6 +765,66 @@ impl GameBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn play_kinds_match_format_table() {
+        let format = include_str!("../../FORMAT.md");
+        let mut rows = format.lines().skip_while(|line| !line.starts_with("| Play Type |"));
+        let header = rows.next().unwrap().trim_matches('|').split('|').map(str::trim).skip(1).collect::<Vec<_>>();
+        let table = rows.skip(1).take_while(|line| line.starts_with('|')).map(|row| {
+            let cells = row.trim_matches('|').split('|').map(str::trim).collect::<Vec<_>>();
+            let fields = header.iter().zip(&cells[1..]).filter(|(_, cell)| **cell == "✓").map(|(column, _)| {
+                column.to_lowercase().replace(' ', "_")
+            }).collect::<Vec<_>>();
+            (cells[0].to_string(), fields)
+        }).collect::<Vec<_>>();
+
+        let registry = PlayType::iter().map(|play_type| {
+            (play_type.to_string(), play_type.fields().iter().map(|field| field.key().to_string()).collect::<Vec<_>>())
+        }).collect::<Vec<_>>();
+        assert_eq!(table, registry);
+    }
+
+    #[test]
+    fn accessors_return_the_fields_of_the_play_type() {
+        let play = Play::StolenBase {
+            inning: Inning { number: 3, top: false },
+            base: Base::Second,
+            runner: "Jane Doe".to_string(),
+            movements: Vec::new(),
+        };
+
+        assert_eq!(play.play_type(), Some(PlayType::StolenBase));
+        assert_eq!(play.inning(), &Inning { number: 3, top: false });
+        assert_eq!(play.base(), Some(Base::Second));
+        assert_eq!(play.runner(), Some("Jane Doe"));
+        assert_eq!(play.movements(), Some(&[][..]));
+        assert_eq!((play.batter(), play.pitcher(), play.catcher(), play.fielders(), play.scoring_runner()), (None, None, None, None, None));
+        assert_eq!(Play::GameAdvisory { inning: Inning { number: 1, top: true } }.movements(), None);
+    }
+
+    #[test]
+    fn play_builder_reports_missing_and_unexpected_fields() {
+        let mut play_builder = PlayBuilder::new();
+        assert_eq!(play_builder.build().unwrap_err().to_string(), "play: missing inning, type");
+
+        play_builder.set_inning(Inning { number: 1, top: true });
+        play_builder.set_play_type(PlayType::Single);
+        play_builder.set_batter("Jane Doe".to_string());
+        play_builder.set_runner("John Doe".to_string());
+        assert_eq!(play_builder.build().unwrap_err(), MissingFields {
+            play_type: Some("Single".to_string()),
+            missing: vec!["pitcher", "movements"],
+            unexpected: vec!["runner"],
+        });
+        assert_eq!(play_builder.build().unwrap_err().to_string(), "Single: missing pitcher, movements; unexpected runner");
+
+        play_builder.runner = None;
+        play_builder.set_pitcher("John Doe".to_string());
+        play_builder.set_movements(Vec::new());
+        assert_eq!(play_builder.build().unwrap().batter(), Some("Jane Doe"));
+    }
 
     #[test]
     fn game_builder_process_one_movement() {
@@ -1313,6 +926,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bases_after_plays_follow_the_runners() {
+        let inning = Inning { number: 1, top: true };
+        let safe = |runner: &str, start_base, end_base| Movement { runner: runner.to_string(), start_base, end_base, is_out: false };
+        let walk = |batter: &str, movements| Play::Walk { inning, batter: batter.to_string(), pitcher: "Person P".to_string(), movements };
+        let plays = [
+            walk("Person A", vec![safe("Person A", Base::Home, Base::First)]),
+            walk("Person B", vec![safe("Person A", Base::First, Base::Second), safe("Person B", Base::Home, Base::First)]),
+        ];
+
+        assert_eq!(bases_after_plays(&plays[..1]), [Some("Person A".to_string()), None, None]);
+        assert_eq!(bases_after_plays(&plays), [Some("Person B".to_string()), Some("Person A".to_string()), None]);
+        assert_eq!(bases_after_plays(&[]), [None, None, None]);
+
+        let next_half_inning = Inning { number: 1, top: false };
+        let single = Play::Single {
+            inning: next_half_inning,
+            batter: "Person C".to_string(),
+            pitcher: "Person Q".to_string(),
+            movements: vec![safe("Person C", Base::Home, Base::First)],
+        };
+        assert_eq!(bases_after_plays(&[plays[0].clone(), plays[1].clone(), single.clone()]), [Some("Person C".to_string()), None, None]);
+        let next_inning = Play::GameAdvisory { inning: Inning { number: 2, top: false } };
+        assert_eq!(bases_after_plays(&[single, next_inning]), [None, None, None]);
+    }
+
     #[test]
     fn game_builder_process_movement_with_out() {
         let mut game_builder = GameBuilder::new();
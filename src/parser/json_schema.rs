@@ -1,10 +1,446 @@
+#[cfg(feature = "python")]
+use pyo3::pyclass;
+use serde_json::json;
+use std::fmt;
+
 pub trait ToRegex {
-    fn to_regex(&self) -> String;
+    fn to_regex_with_style(&self, style: RegexStyle) -> String;
+
+    /// The regex in the canonical style, i.e. with the exact spacing and key order described in `FORMAT.md`.
+    fn to_regex(&self) -> String {
+        self.to_regex_with_style(RegexStyle::Canonical)
+    }
+
+    /// The regex in the given style, rewritten to be safe to compile in `dialect`, or as generated without one.
+    fn to_regex_with(&self, style: RegexStyle, dialect: Option<RegexDialect>) -> String {
+        let regex = self.to_regex_with_style(style);
+        match dialect {
+            Some(dialect) => dialect.translate(&regex),
+            None => regex,
+        }
+    }
+}
+
+/// A regex matching the whitespace that may appear between JSON tokens on a single line.
+const FLEXIBLE_WHITESPACE: &str = r"[ \t]*";
+
+/// How strictly the generated regexes follow the canonical layout of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegexStyle {
+    /// The exact spacing and key order described in `FORMAT.md`, e.g. `{ "a": 1, "b": [1, 2] }`.
+    Canonical,
+    /// Any amount of spaces or tabs between tokens, e.g. `{"a":1,"b":[1,2]}`, but keys in the canonical order.
+    FlexibleWhitespace,
+    /// Flexible whitespace, and the members of objects with at most `max_members` members in any order. The regex for
+    /// an object grows with the factorial of its number of members, so this is only feasible for small objects.
+    AnyKeyOrder { max_members: usize },
+}
+
+impl RegexStyle {
+    /// The style with the given name, one of `"canonical"`, `"flexible_whitespace"` or `"any_key_order"`.
+    /// `max_members` is only used by the `"any_key_order"` style.
+    pub fn from_name(name: &str, max_members: usize) -> Result<Self, String> {
+        match name {
+            "canonical" => Ok(RegexStyle::Canonical),
+            "flexible_whitespace" => Ok(RegexStyle::FlexibleWhitespace),
+            "any_key_order" => Ok(RegexStyle::AnyKeyOrder { max_members }),
+            _ => Err(format!(
+                "unknown regex style {name:?}, expected \"canonical\", \"flexible_whitespace\" or \"any_key_order\"",
+            )),
+        }
+    }
+
+    /// Whitespace that is a single space in the canonical style.
+    fn space(&self) -> &'static str {
+        match self {
+            RegexStyle::Canonical => " ",
+            _ => FLEXIBLE_WHITESPACE,
+        }
+    }
+
+    /// Whitespace that is absent in the canonical style.
+    fn no_space(&self) -> &'static str {
+        match self {
+            RegexStyle::Canonical => "",
+            _ => FLEXIBLE_WHITESPACE,
+        }
+    }
+
+    fn object_open(&self) -> String {
+        format!("\\{{{}", self.space())
+    }
+
+    fn object_close(&self) -> String {
+        format!("{}\\}}", self.space())
+    }
+
+    fn empty_object(&self) -> String {
+        format!("\\{{{}\\}}", self.no_space())
+    }
+
+    fn array_open(&self) -> String {
+        format!("\\[{}", self.no_space())
+    }
+
+    fn array_close(&self) -> String {
+        format!("{}\\]", self.no_space())
+    }
+
+    fn empty_array(&self) -> String {
+        format!("\\[{}\\]", self.no_space())
+    }
+
+    /// The separator between object members or array items.
+    fn separator(&self) -> String {
+        format!("{},{}", self.no_space(), self.space())
+    }
+
+    /// The separator between an object member's key and its value.
+    fn key_separator(&self) -> String {
+        format!("{}:{}", self.no_space(), self.space())
+    }
+}
+
+/// A regex matching a single Unicode letter, in a form understood by both Python's `re` and Rust's `regex`.
+pub const UNICODE_LETTER: &str = r"[^\W\d_]";
+
+/// The characters that have a special meaning in a regex, outside or inside a character class.
+const REGEX_METACHARACTERS: &str = r"\.+*?()|[]{}^$#&-~";
+
+/// Escapes every regex metacharacter in `literal`, so that the result matches `literal` exactly.
+pub fn escape_regex(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if REGEX_METACHARACTERS.contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// A regex matching the `\uXXXX` JSON escape for the given UTF-16 code unit, with hex digits in either case.
+fn json_unicode_escape_regex(code_unit: u16) -> String {
+    let digits = format!("{code_unit:04x}").chars().map(|digit| if digit.is_ascii_alphabetic() {
+        format!("[{digit}{}]", digit.to_ascii_uppercase())
+    } else {
+        digit.to_string()
+    }).collect::<String>();
+
+    format!(r"\\u{digits}")
+}
+
+/// A regex matching `literal` as the contents of a JSON string, i.e. between the quotes.
+///
+/// Characters that JSON requires to be escaped are matched in the form `serde_json` writes them. If
+/// `accept_json_escapes` is true, every character may also be written as a `\uXXXX` escape (or a surrogate pair), and
+/// `/` as `\/`.
+pub fn json_string_literal_regex(literal: &str, accept_json_escapes: bool) -> String {
+    let mut regex = String::new();
+    for c in literal.chars() {
+        let verbatim = match c {
+            '"' => r#"\\""#.to_string(),
+            '\\' => r"\\\\".to_string(),
+            '\n' => r"\\n".to_string(),
+            '\r' => r"\\r".to_string(),
+            '\t' => r"\\t".to_string(),
+            '\u{08}' => r"\\b".to_string(),
+            '\u{0c}' => r"\\f".to_string(),
+            c if c.is_control() && (c as u32) < 0x20 => json_unicode_escape_regex(c as u16),
+            c => escape_regex(&c.to_string()),
+        };
+
+        if !accept_json_escapes {
+            regex.push_str(&verbatim);
+            continue;
+        }
+
+        let mut alternatives = vec![verbatim];
+        let unicode_escape = c.encode_utf16(&mut [0; 2]).iter().map(|code_unit| json_unicode_escape_regex(*code_unit)).collect::<String>();
+        if !alternatives.contains(&unicode_escape) {
+            alternatives.push(unicode_escape);
+        }
+        if c == '/' {
+            alternatives.push(r"\\/".to_string());
+        }
+        regex.push_str(&format!("({})", alternatives.join("|")));
+    }
+
+    regex
+}
+
+/// A regex matching a single digit between `low` and `high` (inclusive).
+fn digit_class_regex(low: u8, high: u8) -> String {
+    match (low, high) {
+        (low, high) if low == high => low.to_string(),
+        (0, 9) => r"\d".to_string(),
+        (low, high) if high == low + 1 => format!("[{low}{high}]"),
+        (low, high) => format!("[{low}-{high}]"),
+    }
+}
+
+/// The patterns matching every digit string between `low` and `high` (inclusive), which must have the same length.
+///
+/// Each pattern is a prefix followed by a number of arbitrary digits.
+fn digit_range_patterns(low: &[u8], high: &[u8]) -> Vec<(String, usize)> {
+    let (Some((&low_first, low_rest)), Some((&high_first, high_rest))) = (low.split_first(), high.split_first()) else {
+        return vec![(String::new(), 0)];
+    };
+    let prefixed = |first: u8, patterns: Vec<(String, usize)>| patterns.into_iter().map(move |(prefix, digits)| {
+        (format!("{first}{prefix}"), digits)
+    });
+
+    if low_first == high_first {
+        return prefixed(low_first, digit_range_patterns(low_rest, high_rest)).collect();
+    }
+
+    let mut patterns = Vec::new();
+
+    // the numbers starting with `low_first`, unless they can be merged into the middle class
+    let mut middle_low = low_first;
+    if low_rest.iter().any(|digit| *digit != 0) {
+        patterns.extend(prefixed(low_first, digit_range_patterns(low_rest, &vec![9; low_rest.len()])));
+        middle_low += 1;
+    }
+
+    // the numbers starting with `high_first`, unless they can be merged into the middle class
+    let mut middle_high = high_first;
+    let mut high_patterns = Vec::new();
+    if high_rest.iter().any(|digit| *digit != 9) {
+        high_patterns.extend(prefixed(high_first, digit_range_patterns(&vec![0; high_rest.len()], high_rest)));
+        middle_high -= 1;
+    }
+
+    if middle_low <= middle_high {
+        patterns.push((digit_class_regex(middle_low, middle_high), low_rest.len()));
+    }
+    patterns.extend(high_patterns);
+
+    patterns
+}
+
+/// A regex matching every non-negative integer between `min` and `max` (inclusive), without leading zeros.
+fn natural_range_regex(min: u64, max: u64) -> String {
+    let digits_of = |n: u64, length: usize| format!("{n:0length$}").bytes().map(|digit| digit - b'0').collect::<Vec<_>>();
+    let length_of = |n: u64| n.to_string().len();
+
+    // (prefix, fewest trailing digits, most trailing digits)
+    let mut patterns: Vec<(String, usize, usize)> = Vec::new();
+    for length in length_of(min)..=length_of(max) {
+        let low = if length == 1 { min } else { min.max(10u64.pow(length as u32 - 1)) };
+        let high = if length >= 20 { max } else { max.min(10u64.pow(length as u32) - 1) };
+
+        for (prefix, digits) in digit_range_patterns(&digits_of(low, length), &digits_of(high, length)) {
+            // merge `[1-9]\d` and `[1-9]\d{2}` into `[1-9]\d{1,2}`
+            match patterns.last_mut() {
+                Some((last_prefix, _, last_max_digits)) if *last_prefix == prefix && *last_max_digits + 1 == digits => {
+                    *last_max_digits = digits;
+                }
+                _ => patterns.push((prefix, digits, digits)),
+            }
+        }
+    }
+
+    patterns.into_iter().map(|(prefix, min_digits, max_digits)| match (min_digits, max_digits) {
+        (0, 0) => prefix,
+        (1, 1) => format!(r"{prefix}\d"),
+        (min_digits, max_digits) if min_digits == max_digits => format!(r"{prefix}\d{{{min_digits}}}"),
+        (min_digits, max_digits) => format!(r"{prefix}\d{{{min_digits},{max_digits}}}"),
+    }).collect::<Vec<_>>().join("|")
+}
+
+/// Wraps `regex` in a group if it is an alternation.
+fn group_alternation(regex: String) -> String {
+    if regex.contains('|') {
+        format!("({regex})")
+    } else {
+        regex
+    }
+}
+
+/// A regex matching every integer between `min` and `max` (inclusive), in the form JSON writes them.
+pub fn integer_range_regex(min: i64, max: i64) -> String {
+    assert!(min <= max, "the minimum {min} should not be greater than the maximum {max}");
+
+    let mut alternatives = Vec::new();
+    if min < 0 {
+        let closest_to_zero = if max < 0 { max.unsigned_abs() } else { 1 };
+        alternatives.push(format!("-{}", group_alternation(natural_range_regex(closest_to_zero, min.unsigned_abs()))));
+    }
+    if max >= 0 {
+        alternatives.push(natural_range_regex(min.max(0) as u64, max as u64));
+    }
+
+    alternatives.join("|")
+}
+
+/// A regex matching every natural number from `min` upwards.
+fn natural_at_least_regex(min: u64) -> String {
+    let length = min.to_string().len();
+    let same_length = natural_range_regex(min, 10u64.pow(length as u32) - 1);
+
+    format!(r"{same_length}|[1-9]\d{{{length},}}")
+}
+
+/// A regex matching every integer from `min` upwards, in the form JSON writes them.
+pub fn integer_at_least_regex(min: i64) -> String {
+    if min >= 0 {
+        natural_at_least_regex(min as u64)
+    } else {
+        format!("-{}|{}", group_alternation(natural_range_regex(1, min.unsigned_abs())), natural_at_least_regex(0))
+    }
+}
+
+/// A regex matching every integer up to `max`, in the form JSON writes them.
+pub fn integer_at_most_regex(max: i64) -> String {
+    if max >= 0 {
+        format!(r"-[1-9]\d*|{}", natural_range_regex(0, max as u64))
+    } else {
+        format!("-{}", group_alternation(natural_at_least_regex(max.unsigned_abs())))
+    }
 }
 
+/// A regex matching every decimal number between `min` and `max` (inclusive) with at most `max_fraction_digits` digits
+/// after the decimal point, in the form JSON writes them.
+pub fn number_range_regex(min: i64, max: i64, max_fraction_digits: usize) -> String {
+    assert!(min <= max, "the minimum {min} should not be greater than the maximum {max}");
+    if max_fraction_digits == 0 {
+        return integer_range_regex(min, max);
+    }
+
+    let any_fraction = format!(r"(\.\d{{1,{max_fraction_digits}}})?");
+    let zero_fraction = format!(r"(\.0{{1,{max_fraction_digits}}})?");
+    // the numbers whose integer part is between `low` and `high`, where only `high` itself may not have a fraction
+    let magnitudes = |low: u64, high: u64| if low < high {
+        format!("{}{any_fraction}|{high}{zero_fraction}", group_alternation(natural_range_regex(low, high - 1)))
+    } else {
+        format!("{high}{zero_fraction}")
+    };
+
+    let mut alternatives = Vec::new();
+    if min < 0 {
+        let closest_to_zero = if max < 0 { max.unsigned_abs() } else { 0 };
+        alternatives.push(format!("-{}", group_alternation(magnitudes(closest_to_zero, min.unsigned_abs()))));
+    }
+    if max >= 0 {
+        alternatives.push(magnitudes(min.max(0) as u64, max as u64));
+    }
+
+    alternatives.join("|")
+}
+
+/// A regex matching one or more Unicode letters, interspersed with any of the given extra characters.
+pub fn unicode_text_regex(extra_characters: &str) -> String {
+    format!("({UNICODE_LETTER}|[{}])+", escape_regex(extra_characters))
+}
+
+/// The letters matched by `UNICODE_LETTER` in dialects without Unicode-aware shorthand classes: the Latin, Greek and
+/// Cyrillic scripts, and the combining diacritical marks of decomposed accented letters.
+const EXPLICIT_LETTER_RANGES: &str = "A-Za-z\u{aa}\u{b5}\u{ba}\u{c0}-\u{d6}\u{d8}-\u{f6}\u{f8}-\u{24f}\u{300}-\u{36f}\u{370}-\u{3ff}\u{400}-\u{4ff}\u{1e00}-\u{1eff}";
+
+/// A regex engine that consumes the generated regexes.
+///
+/// The regexes from `ToRegex` use a syntax common to the engines, but they disagree on some details: `\d` matches
+/// non-ASCII digits in Python's `re` and Rust's `regex`, Rust's `regex` treats `&&`, `--` and `~~` in a character class
+/// as set operations, and `interegular` (used by `outlines`) has no Unicode-aware shorthand classes. Every dialect
+/// also uses non-capturing groups, since the generated regexes nest far more groups than anyone needs to capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegexDialect {
+    /// Python's `re` module, also used by `exrex`.
+    PythonRe,
+    /// Rust's `regex` crate.
+    RustRegex,
+    /// The `interegular` package, used by `outlines` to build finite-state machines.
+    Interegular,
+}
+
+impl RegexDialect {
+    /// The dialect with the given name, one of `"python_re"`, `"rust_regex"` or `"interegular"`.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "python_re" => Ok(RegexDialect::PythonRe),
+            "rust_regex" => Ok(RegexDialect::RustRegex),
+            "interegular" => Ok(RegexDialect::Interegular),
+            _ => Err(format!("unknown regex dialect {name:?}, expected \"python_re\", \"rust_regex\" or \"interegular\"")),
+        }
+    }
+
+    /// Rewrites a regex generated by `ToRegex` into a form that is safe to compile in this dialect.
+    pub fn translate(&self, regex: &str) -> String {
+        let mut translated = String::with_capacity(regex.len());
+        let mut in_class = false;
+        let mut rest = regex;
+
+        while let Some(c) = rest.chars().next() {
+            if !in_class && rest.starts_with(UNICODE_LETTER) {
+                translated.push_str(&self.unicode_letter());
+                rest = &rest[UNICODE_LETTER.len()..];
+                continue;
+            }
+
+            rest = &rest[c.len_utf8()..];
+            match c {
+                '\\' => {
+                    let Some(escaped) = rest.chars().next() else {
+                        translated.push('\\');
+                        break;
+                    };
+                    rest = &rest[escaped.len_utf8()..];
+                    translated.push_str(&self.escape(escaped, in_class));
+                }
+                '[' if !in_class => {
+                    in_class = true;
+                    translated.push('[');
+                    if let Some(stripped) = rest.strip_prefix('^') {
+                        translated.push('^');
+                        rest = stripped;
+                    }
+                }
+                ']' if in_class => {
+                    in_class = false;
+                    translated.push(']');
+                }
+                // a character class set operation in Rust's `regex`, and a future one in Python's `re`
+                '&' | '~' if in_class => {
+                    translated.push('\\');
+                    translated.push(c);
+                }
+                '(' if !in_class && !rest.starts_with('?') => translated.push_str("(?:"),
+                c => translated.push(c),
+            }
+        }
+
+        translated
+    }
+
+    fn unicode_letter(&self) -> String {
+        match self {
+            RegexDialect::PythonRe | RegexDialect::RustRegex => UNICODE_LETTER.to_string(),
+            RegexDialect::Interegular => format!("[{EXPLICIT_LETTER_RANGES}]"),
+        }
+    }
+
+    /// The translation of the escape sequence `\{escaped}`.
+    fn escape(&self, escaped: char, in_class: bool) -> String {
+        match (escaped, in_class) {
+            // only ASCII digits are valid in JSON numbers
+            ('d', false) => "[0-9]".to_string(),
+            ('d', true) => "0-9".to_string(),
+            // `interegular` only understands escapes of characters that are special where they appear
+            ('#' | '&' | '~', _) | ('-', false) if *self == RegexDialect::Interegular => escaped.to_string(),
+            (escaped, _) => format!("\\{escaped}"),
+        }
+    }
+}
+
+/// A member of a JSON object.
+#[derive(Debug, Clone, PartialEq)]
 pub struct KeyValueType {
-    key: String,
-    value: Box<JsonType>,
+    pub key: String,
+    pub value: Box<JsonType>,
+    /// Whether the member must be present. Optional members may be left out, but keep their position when present.
+    pub required: bool,
 }
 
 impl KeyValueType {
@@ -12,58 +448,115 @@ impl KeyValueType {
         Self {
             key,
             value: Box::new(value),
+            required: true,
+        }
+    }
+
+    pub fn optional(key: String, value: JsonType) -> Self {
+        Self {
+            required: false,
+            ..Self::new(key, value)
         }
     }
 }
 
 impl ToRegex for KeyValueType {
-    fn to_regex(&self) -> String {
-        format!("\"{}\": {}", self.key, self.value.to_regex())
+    fn to_regex_with_style(&self, style: RegexStyle) -> String {
+        format!("\"{}\"{}{}", json_string_literal_regex(&self.key, false), style.key_separator(), self.value.to_regex_with_style(style))
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum JsonType {
-    Boolean { regex: String },
-    Integer { regex: String },
-    String { regex: String },
-    Array(Box<JsonType>),
+    Null,
+    Boolean,
+    Integer { pattern: String, minimum: Option<i64>, maximum: Option<i64> },
+    Number { pattern: String, minimum: Option<i64>, maximum: Option<i64> },
+    String { pattern: String },
+    /// One of a fixed set of values, of any type.
+    Enum(Vec<serde_json::Value>),
+    Array {
+        items: Box<JsonType>,
+        /// The fewest items the array may have.
+        min_items: usize,
+        /// The most items the array may have, if limited.
+        max_items: Option<usize>,
+        /// Whether every item must be different. This cannot be expressed as a regex.
+        unique: bool,
+    },
     Object(Vec<KeyValueType>),
     Union(Vec<JsonType>),
 }
 
 impl JsonType {
+    pub fn null() -> Self {
+        Self::Null
+    }
+
     pub fn boolean() -> Self {
-        Self::boolean_with_options(vec![true, false])
+        Self::Boolean
     }
 
     pub fn boolean_with_options(options: Vec<bool>) -> Self {
-        let regex = options.iter().map(|b| b.to_string()).collect::<Vec<_>>().join("|");
-        Self::Boolean {
-            regex: format!("({regex})"),
-        }
+        Self::enumeration(options.into_iter().map(serde_json::Value::from).collect())
     }
 
     pub fn integer_with_regex(regex: &str) -> Self {
         Self::Integer {
-            regex: format!("({regex})"),
+            pattern: regex.to_string(),
+            minimum: None,
+            maximum: None,
         }
     }
 
     pub fn integer() -> Self {
-        Self::integer_with_regex(r"[1-9]\d+")
+        Self::integer_with_regex(r"0|-?[1-9]\d*")
+    }
+
+    /// An integer between `min` and `max` (inclusive).
+    pub fn integer_range(min: i64, max: i64) -> Self {
+        Self::Integer {
+            pattern: integer_range_regex(min, max),
+            minimum: Some(min),
+            maximum: Some(max),
+        }
+    }
+
+    /// An integer that is at least `min`.
+    pub fn integer_at_least(min: i64) -> Self {
+        Self::Integer {
+            pattern: integer_at_least_regex(min),
+            minimum: Some(min),
+            maximum: None,
+        }
     }
 
-    pub fn integer_max_digits(max_digits: usize) -> Self {
-        Self::integer_with_regex(&format!(r"[1-9]\d{{0,{}}}", max_digits - 1))
+    /// An integer that is at most `max`.
+    pub fn integer_at_most(max: i64) -> Self {
+        Self::Integer {
+            pattern: integer_at_most_regex(max),
+            minimum: None,
+            maximum: Some(max),
+        }
     }
 
     pub fn integer_with_options(options: Vec<usize>) -> Self {
-        Self::integer_with_regex(&options.iter().map(|i| i.to_string()).collect::<Vec<_>>().join("|"))
+        Self::enumeration(options.into_iter().map(serde_json::Value::from).collect())
+    }
+
+    /// A decimal number between `min` and `max` (inclusive), with at most `max_fraction_digits` digits after the
+    /// decimal point.
+    pub fn number_range(min: i64, max: i64, max_fraction_digits: usize) -> Self {
+        Self::Number {
+            pattern: number_range_regex(min, max, max_fraction_digits),
+            minimum: Some(min),
+            maximum: Some(max),
+        }
     }
 
     pub fn string_with_regex(regex: &str) -> Self {
         Self::String {
-            regex: format!("\"({regex})\""),
+            pattern: regex.to_string(),
         }
     }
 
@@ -71,14 +564,48 @@ impl JsonType {
         Self::string_with_regex("[^\"]*")
     }
 
+    /// A string that is one of the given options.
+    pub fn string_enum<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Self {
+        Self::enumeration(options.into_iter().map(|option| serde_json::Value::String(option.into())).collect())
+    }
+
+    /// A value that is one of the given options.
+    pub fn enumeration(options: Vec<serde_json::Value>) -> Self {
+        Self::Enum(options)
+    }
+
+    /// Either a value of the given type or `null`.
+    pub fn nullable(value: JsonType) -> Self {
+        Self::Union(vec![value, Self::Null])
+    }
+
     pub fn array(item_type: JsonType) -> Self {
-        Self::Array(Box::new(item_type))
+        Self::array_with_constraints(item_type, 0, None, false)
+    }
+
+    /// An array with between `min_items` and `max_items` (inclusive) items, which must all be different if `unique`.
+    pub fn array_with_constraints(item_type: JsonType, min_items: usize, max_items: Option<usize>, unique: bool) -> Self {
+        if let Some(max_items) = max_items {
+            assert!(min_items <= max_items, "the minimum {min_items} items should not be more than the maximum {max_items}");
+        }
+
+        Self::Array {
+            items: Box::new(item_type),
+            min_items,
+            max_items,
+            unique,
+        }
     }
 
     pub fn key_value(key: &str, value: JsonType) -> KeyValueType {
         KeyValueType::new(key.to_string(), value)
     }
 
+    /// An object member that may be left out.
+    pub fn optional_key_value(key: &str, value: JsonType) -> KeyValueType {
+        KeyValueType::optional(key.to_string(), value)
+    }
+
     pub fn object(items: Vec<KeyValueType>) -> Self {
         Self::Object(items)
     }
@@ -88,34 +615,918 @@ impl JsonType {
     }
 }
 
+/// A regex quantifier for between `min` and `max` (inclusive) repetitions.
+fn quantifier(min: usize, max: Option<usize>) -> String {
+    match (min, max) {
+        (0, None) => "*".to_string(),
+        (1, None) => "+".to_string(),
+        (min, None) => format!("{{{min},}}"),
+        (0, Some(1)) => "?".to_string(),
+        (min, Some(max)) if min == max => format!("{{{min}}}"),
+        (min, Some(max)) => format!("{{{min},{max}}}"),
+    }
+}
+
+/// The regex for an array of between `min_items` and `max_items` (inclusive) items matching `item_regex`.
+fn array_regex(item_regex: &str, min_items: usize, max_items: Option<usize>, style: RegexStyle) -> String {
+    let (open, close, separator) = (style.array_open(), style.array_close(), style.separator());
+    if max_items == Some(0) {
+        return style.empty_array();
+    }
+
+    // the first item is matched on its own, so the rest are each preceded by a separator
+    let rest = |min: usize| match quantifier(min, max_items.map(|max_items| max_items - 1)).as_str() {
+        "{1}" => format!("{separator}{item_regex}"),
+        "{0}" => String::new(),
+        quantifier => format!("({separator}{item_regex}){quantifier}"),
+    };
+    if min_items == 0 {
+        format!("{open}({item_regex}{})?{close}", rest(0))
+    } else {
+        format!("{open}{item_regex}{}{close}", rest(min_items - 1))
+    }
+}
+
+/// The regex for an object with the given members, in order, where optional members may be left out.
+fn ordered_object_regex(items: &[&KeyValueType], style: RegexStyle) -> String {
+    let (open, close, separator) = (style.object_open(), style.object_close(), style.separator());
+    let item_regexes = items.iter().map(|item| item.to_regex_with_style(style)).collect::<Vec<_>>();
+
+    let Some(first_required) = items.iter().position(|item| item.required) else {
+        // every member is optional, so any of them could be the first one present, or the object could be empty
+        let mut alternatives = (0..items.len()).map(|first| {
+            let rest = item_regexes[first + 1..].iter().map(|regex| format!("({separator}{regex})?")).collect::<String>();
+            format!("{open}{}{rest}{close}", item_regexes[first])
+        }).collect::<Vec<_>>();
+        alternatives.push(style.empty_object());
+
+        return format!("({})", alternatives.join("|"));
+    };
+
+    // optional members before the first required one are followed by a separator, and those after it are preceded
+    // by one
+    let body = items.iter().zip(&item_regexes).enumerate().map(|(i, (item, regex))| {
+        if i < first_required {
+            format!("({regex}{separator})?")
+        } else if i == first_required {
+            regex.clone()
+        } else if item.required {
+            format!("{separator}{regex}")
+        } else {
+            format!("({separator}{regex})?")
+        }
+    }).collect::<String>();
+
+    format!("{open}{body}{close}")
+}
+
+/// Every ordering of the given items.
+fn permutations<'a, T>(items: &[&'a T]) -> Vec<Vec<&'a T>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+
+    (0..items.len()).flat_map(|first| {
+        let mut rest = items.to_vec();
+        let first = rest.remove(first);
+        permutations(&rest).into_iter().map(move |mut permutation| {
+            permutation.insert(0, first);
+            permutation
+        })
+    }).collect()
+}
+
+/// The regex for an object with the given members, in the order allowed by `style`.
+fn object_regex(items: &[KeyValueType], style: RegexStyle) -> String {
+    let items = items.iter().collect::<Vec<_>>();
+    match style {
+        RegexStyle::AnyKeyOrder { max_members } if items.len() > 1 && items.len() <= max_members => format!(
+            "({})",
+            permutations(&items).iter().map(|permutation| ordered_object_regex(permutation, style)).collect::<Vec<_>>().join("|"),
+        ),
+        _ => ordered_object_regex(&items, style),
+    }
+}
+
 impl ToRegex for JsonType {
-    fn to_regex(&self) -> String {
+    fn to_regex_with_style(&self, style: RegexStyle) -> String {
         match self {
-            JsonType::Boolean { regex } | JsonType::Integer { regex } | JsonType::String { regex } => regex.to_string(),
-            JsonType::Array(items) => format!(
-                "\\[{}(, {})*\\]",
-                items.to_regex(),
-                items.to_regex(),
-            ),
-            JsonType::Object(items) => format!(
-                "\\{{ {} \\}}",
-                items.iter().map(|item| item.to_regex()).collect::<Vec<_>>().join(", "),
-            ),
+            JsonType::Null => "null".to_string(),
+            JsonType::Boolean => "(true|false)".to_string(),
+            JsonType::Integer { pattern, .. } | JsonType::Number { pattern, .. } => format!("({pattern})"),
+            JsonType::String { pattern } => format!("\"({pattern})\""),
+            JsonType::Enum(options) => if options.iter().all(serde_json::Value::is_string) {
+                format!(
+                    "\"({})\"",
+                    options.iter().filter_map(|option| option.as_str()).map(|option| json_string_literal_regex(option, false)).collect::<Vec<_>>().join("|"),
+                )
+            } else {
+                format!(
+                    "({})",
+                    options.iter().map(|option| escape_regex(&option.to_string())).collect::<Vec<_>>().join("|"),
+                )
+            },
+            JsonType::Array { items, min_items, max_items, .. } => array_regex(&items.to_regex_with_style(style), *min_items, *max_items, style),
+            JsonType::Object(items) => object_regex(items, style),
             JsonType::Union(items) => format!(
                 "({})",
-                items.iter().map(|item| format!("({})", item.to_regex())).collect::<Vec<_>>().join("|"),
+                items.iter().map(|item| format!("({})", item.to_regex_with_style(style))).collect::<Vec<_>>().join("|"),
             ),
         }
     }
 }
 
+pub trait ToJsonSchema {
+    fn to_json_schema(&self) -> serde_json::Value;
+}
+
+impl ToJsonSchema for JsonType {
+    /// The equivalent JSON Schema document. String patterns are matched against the JSON text between the quotes, so
+    /// they only differ from the regex for strings containing escaped characters.
+    fn to_json_schema(&self) -> serde_json::Value {
+        match self {
+            JsonType::Null => json!({ "type": "null" }),
+            JsonType::Boolean => json!({ "type": "boolean" }),
+            JsonType::Integer { minimum, maximum, .. } | JsonType::Number { minimum, maximum, .. } => {
+                let mut schema = json!({ "type": if matches!(self, JsonType::Integer { .. }) { "integer" } else { "number" } });
+                if let Some(minimum) = minimum {
+                    schema["minimum"] = json!(minimum);
+                }
+                if let Some(maximum) = maximum {
+                    schema["maximum"] = json!(maximum);
+                }
+                schema
+            }
+            JsonType::String { pattern } => json!({ "type": "string", "pattern": format!("^({pattern})$") }),
+            JsonType::Enum(options) => json!({ "enum": options }),
+            JsonType::Array { items, min_items, max_items, unique } => {
+                let mut schema = json!({ "type": "array", "items": items.to_json_schema() });
+                if *min_items > 0 {
+                    schema["minItems"] = json!(min_items);
+                }
+                if let Some(max_items) = max_items {
+                    schema["maxItems"] = json!(max_items);
+                }
+                if *unique {
+                    schema["uniqueItems"] = json!(true);
+                }
+                schema
+            }
+            JsonType::Object(items) => json!({
+                "type": "object",
+                "properties": items.iter().map(|item| (item.key.clone(), item.value.to_json_schema())).collect::<serde_json::Map<_, _>>(),
+                "required": items.iter().filter(|item| item.required).map(|item| item.key.clone()).collect::<Vec<_>>(),
+                "additionalProperties": false,
+            }),
+            JsonType::Union(items) => json!({ "anyOf": items.iter().map(|item| item.to_json_schema()).collect::<Vec<_>>() }),
+        }
+    }
+}
+
+/// The keywords that only annotate a JSON Schema, and so are ignored when importing it.
+const ANNOTATION_KEYWORDS: [&str; 8] = ["$schema", "$id", "$comment", "title", "description", "examples", "default", "deprecated"];
+
+/// A reason a JSON Schema document could not be imported as a `JsonType`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    /// The JSON pointer to the offending subschema, e.g. `/properties/movements/items`.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl SchemaError {
+    pub fn new(pointer: &str, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() { "/" } else { &self.pointer };
+        write!(f, "{pointer}: {}", self.message)
+    }
+}
+
+impl JsonType {
+    /// Imports a JSON Schema document, so that other line formats described in JSON Schema can reuse the regex
+    /// emitters.
+    ///
+    /// Only a subset of JSON Schema is supported: the `null`, `boolean`, `integer`, `number`, `string`, `array` and
+    /// `object` types, `enum`, `const` and `anyOf`, which may be given a `type` that all of their values are of.
+    /// Integers may have a `minimum`, a `maximum` or both, strings a `pattern` anchored with `^` and `$`, arrays
+    /// `items`, `minItems`, `maxItems` and `uniqueItems`, and objects `properties`, `required` and
+    /// `"additionalProperties": false`. Object members keep the order of `properties`. Any other keyword is an error,
+    /// except annotations such as `title` and `description`.
+    pub fn from_json_schema(schema: &serde_json::Value) -> Result<Self, SchemaError> {
+        Self::from_json_schema_at("", schema)
+    }
+
+    fn from_json_schema_at(pointer: &str, schema: &serde_json::Value) -> Result<Self, SchemaError> {
+        let serde_json::Value::Object(schema) = schema else {
+            return Err(SchemaError::new(pointer, format!("expected a schema object, found {schema}")));
+        };
+
+        let keyword_pointer = |keyword: &str| format!("{pointer}/{}", json_pointer_token(keyword));
+        let mut used_keywords = Vec::new();
+        let mut keyword = |keyword: &'static str| {
+            used_keywords.push(keyword);
+            schema.get(keyword)
+        };
+        let usize_keyword = |name: &str, value: &serde_json::Value| value.as_u64().map(|value| value as usize).ok_or_else(|| {
+            SchemaError::new(&keyword_pointer(name), format!("expected a non-negative integer, found {value}"))
+        });
+        let i64_keyword = |name: &str, value: &serde_json::Value| value.as_i64().ok_or_else(|| {
+            SchemaError::new(&keyword_pointer(name), format!("expected an integer, found {value}"))
+        });
+
+        let combined = if let Some(options) = keyword("enum") {
+            let serde_json::Value::Array(options) = options else {
+                return Err(SchemaError::new(&keyword_pointer("enum"), format!("expected an array, found {options}")));
+            };
+            Some(("enum", Self::enumeration(options.clone())))
+        } else if let Some(option) = keyword("const") {
+            Some(("const", Self::enumeration(vec![option.clone()])))
+        } else if let Some(alternatives) = keyword("anyOf") {
+            let serde_json::Value::Array(alternatives) = alternatives else {
+                return Err(SchemaError::new(&keyword_pointer("anyOf"), format!("expected an array, found {alternatives}")));
+            };
+            Some(("anyOf", Self::union(alternatives.iter().enumerate().map(|(i, alternative)| {
+                Self::from_json_schema_at(&format!("{}/{i}", keyword_pointer("anyOf")), alternative)
+            }).collect::<Result<_, _>>()?)))
+        } else {
+            None
+        };
+
+        let json_type = if let Some((combinator, json_type)) = combined {
+            // a type next to them only restates what the values already are
+            if let Some(declared) = keyword("type")
+                && !declared.as_str().is_some_and(|declared| json_type.is_of_schema_type(declared))
+            {
+                return Err(SchemaError::new(
+                    &keyword_pointer("type"),
+                    format!("type {declared} does not match the values allowed by {combinator}"),
+                ));
+            }
+            json_type
+        } else {
+            match keyword("type").map(|json_type| (json_type, json_type.as_str())) {
+                Some((_, Some("null"))) => Self::null(),
+                Some((_, Some("boolean"))) => Self::boolean(),
+                Some((_, Some("integer"))) => match (keyword("minimum"), keyword("maximum")) {
+                    (None, None) => Self::integer(),
+                    (Some(minimum), None) => Self::integer_at_least(i64_keyword("minimum", minimum)?),
+                    (None, Some(maximum)) => Self::integer_at_most(i64_keyword("maximum", maximum)?),
+                    (Some(minimum), Some(maximum)) => match (minimum.as_i64(), maximum.as_i64()) {
+                        (Some(minimum), Some(maximum)) if minimum <= maximum => Self::integer_range(minimum, maximum),
+                        _ => return Err(SchemaError::new(
+                            pointer,
+                            format!("expected integer bounds with minimum <= maximum, found {minimum} and {maximum}"),
+                        )),
+                    },
+                },
+                Some((_, Some("number"))) => Self::Number {
+                    pattern: r"-?(0|[1-9]\d*)(\.\d+)?([eE][+-]?\d+)?".to_string(),
+                    minimum: None,
+                    maximum: None,
+                },
+                Some((_, Some("string"))) => match keyword("pattern") {
+                    None => Self::string(),
+                    Some(pattern) => match pattern.as_str().and_then(|pattern| pattern.strip_prefix('^')?.strip_suffix('$')) {
+                        Some(pattern) => Self::string_with_regex(pattern),
+                        None => return Err(SchemaError::new(
+                            &keyword_pointer("pattern"),
+                            format!("expected a pattern anchored with ^ and $, found {pattern}"),
+                        )),
+                    },
+                },
+                Some((_, Some("array"))) => {
+                    let Some(items) = keyword("items") else {
+                        return Err(SchemaError::new(pointer, "arrays must have an items schema"));
+                    };
+                    let items = Self::from_json_schema_at(&keyword_pointer("items"), items)?;
+                    let min_items = keyword("minItems").map(|value| usize_keyword("minItems", value)).transpose()?.unwrap_or(0);
+                    let max_items = keyword("maxItems").map(|value| usize_keyword("maxItems", value)).transpose()?;
+                    if let Some(max_items) = max_items && min_items > max_items {
+                        return Err(SchemaError::new(
+                            pointer,
+                            format!("expected item counts with minItems <= maxItems, found {min_items} and {max_items}"),
+                        ));
+                    }
+                    let unique = match keyword("uniqueItems") {
+                        None => false,
+                        Some(serde_json::Value::Bool(unique)) => *unique,
+                        Some(unique) => return Err(SchemaError::new(
+                            &keyword_pointer("uniqueItems"),
+                            format!("expected a boolean, found {unique}"),
+                        )),
+                    };
+                    Self::array_with_constraints(items, min_items, max_items, unique)
+                }
+                Some((_, Some("object"))) => {
+                    match keyword("additionalProperties") {
+                        None | Some(serde_json::Value::Bool(false)) => {}
+                        Some(_) => return Err(SchemaError::new(
+                            &keyword_pointer("additionalProperties"),
+                            "only \"additionalProperties\": false is supported, since members are matched in order",
+                        )),
+                    }
+
+                    let required = match keyword("required") {
+                        None => Vec::new(),
+                        Some(serde_json::Value::Array(required)) => required.iter().map(|key| key.as_str().ok_or_else(|| {
+                            SchemaError::new(&keyword_pointer("required"), format!("expected property names, found {key}"))
+                        })).collect::<Result<Vec<_>, _>>()?,
+                        Some(required) => return Err(SchemaError::new(
+                            &keyword_pointer("required"),
+                            format!("expected an array, found {required}"),
+                        )),
+                    };
+
+                    let properties = match keyword("properties") {
+                        None => serde_json::Map::new(),
+                        Some(serde_json::Value::Object(properties)) => properties.clone(),
+                        Some(properties) => return Err(SchemaError::new(
+                            &keyword_pointer("properties"),
+                            format!("expected an object, found {properties}"),
+                        )),
+                    };
+                    if let Some(missing) = required.iter().find(|key| !properties.contains_key(**key)) {
+                        return Err(SchemaError::new(&keyword_pointer("required"), format!("the required property {missing:?} is not in properties")));
+                    }
+
+                    Self::object(properties.iter().map(|(key, value)| {
+                        let value = Self::from_json_schema_at(&format!("{}/{}", keyword_pointer("properties"), json_pointer_token(key)), value)?;
+                        Ok(if required.contains(&key.as_str()) {
+                            KeyValueType::new(key.clone(), value)
+                        } else {
+                            KeyValueType::optional(key.clone(), value)
+                        })
+                    }).collect::<Result<_, _>>()?)
+                }
+                Some((json_type, _)) => return Err(SchemaError::new(&keyword_pointer("type"), format!("unsupported type {json_type}"))),
+                None => return Err(SchemaError::new(pointer, "expected a type, enum, const or anyOf")),
+            }
+        };
+
+        if let Some(unsupported) = schema.keys().find(|key| !used_keywords.contains(&key.as_str()) && !ANNOTATION_KEYWORDS.contains(&key.as_str())) {
+            return Err(SchemaError::new(&keyword_pointer(unsupported), format!("unsupported keyword {unsupported:?}")));
+        }
+
+        Ok(json_type)
+    }
+
+    /// Whether every value of this type is of the given JSON Schema type, e.g. `"string"`.
+    fn is_of_schema_type(&self, schema_type: &str) -> bool {
+        match self {
+            JsonType::Null => schema_type == "null",
+            JsonType::Boolean => schema_type == "boolean",
+            JsonType::Integer { .. } => matches!(schema_type, "integer" | "number"),
+            JsonType::Number { .. } => schema_type == "number",
+            JsonType::String { .. } => schema_type == "string",
+            JsonType::Enum(options) => options.iter().all(|option| value_is_of_schema_type(option, schema_type)),
+            JsonType::Array { .. } => schema_type == "array",
+            JsonType::Object(_) => schema_type == "object",
+            JsonType::Union(alternatives) => alternatives.iter().all(|alternative| {
+                alternative.is_of_schema_type(schema_type)
+            }),
+        }
+    }
+}
+
+/// Whether `value` is of the given JSON Schema type, e.g. `"string"`. As in JSON Schema, numbers without a fractional
+/// part are integers.
+fn value_is_of_schema_type(value: &serde_json::Value, schema_type: &str) -> bool {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => schema_type == "null",
+        Value::Bool(_) => schema_type == "boolean",
+        Value::Number(number) => {
+            schema_type == "number" || schema_type == "integer" && number.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        Value::String(_) => schema_type == "string",
+        Value::Array(_) => schema_type == "array",
+        Value::Object(_) => schema_type == "object",
+    }
+}
+
+/// A way in which a JSON value does not match a `JsonType`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+pub struct Violation {
+    /// The JSON pointer to the offending value, e.g. `/movements/2/end_base`.
+    pub pointer: String,
+    /// What was expected at that location, e.g. the regex that the value should match.
+    pub expected: String,
+    /// What was found at that location, as JSON, or `"nothing"` if a member is missing.
+    pub found: String,
+}
+
+impl Violation {
+    pub fn new(pointer: &str, expected: impl Into<String>, found: &serde_json::Value) -> Self {
+        Self {
+            pointer: pointer.to_string(),
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() { "/" } else { &self.pointer };
+        write!(f, "{pointer}: expected {}, found {}", self.expected, self.found)
+    }
+}
+
+/// Escapes an object key for use as a JSON pointer reference token.
+pub fn json_pointer_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Whether `text` matches `pattern` in its entirety. The size limit is raised as for the parser's own regexes, since
+/// patterns alternating over player names are well past the default one.
+fn matches_entirely(pattern: &str, text: &str) -> Result<bool, regex::Error> {
+    let regex = regex::RegexBuilder::new(&format!("^({pattern})$")).size_limit(1 << 30).build()?;
+
+    Ok(regex.is_match(text))
+}
+
+/// Checks that `text`, the JSON text of `value`, matches `pattern`, reporting `expected` if it does not and the compile
+/// error if the pattern cannot be compiled.
+fn check_pattern(pointer: &str, pattern: &str, text: &str, expected: String, value: &serde_json::Value, violations: &mut Vec<Violation>) {
+    match matches_entirely(pattern, text) {
+        Ok(true) => {}
+        Ok(false) => violations.push(Violation::new(pointer, expected, value)),
+        Err(error) => violations.push(Violation::new(pointer, format!("{expected}, but the pattern does not compile: {error}"), value)),
+    }
+}
+
+impl JsonType {
+    /// Checks `value` against this type, returning every violation found.
+    pub fn validate(&self, value: &serde_json::Value) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        self.validate_at("", value, &mut violations);
+
+        violations
+    }
+
+    fn validate_at(&self, pointer: &str, value: &serde_json::Value, violations: &mut Vec<Violation>) {
+        use serde_json::Value;
+
+        match (self, value) {
+            (JsonType::Null, Value::Null) | (JsonType::Boolean, Value::Bool(_)) => {}
+            (JsonType::Integer { pattern, .. }, Value::Number(number)) if number.is_i64() || number.is_u64() => {
+                check_pattern(pointer, pattern, &number.to_string(), format!("an integer matching {}", self.to_regex()), value, violations);
+            }
+            (JsonType::Number { pattern, .. }, Value::Number(number)) => {
+                check_pattern(pointer, pattern, &number.to_string(), format!("a number matching {}", self.to_regex()), value, violations);
+            }
+            (JsonType::String { pattern }, Value::String(_)) => {
+                // the regex matches the string as it is written in JSON, i.e. with escapes
+                let json = value.to_string();
+                check_pattern(pointer, pattern, &json[1..json.len() - 1], format!("a string matching {}", self.to_regex()), value, violations);
+            }
+            (JsonType::Enum(options), value) => {
+                if !options.contains(value) {
+                    violations.push(Violation::new(pointer, format!("one of {}", self.to_regex()), value));
+                }
+            }
+            (JsonType::Array { items, min_items, max_items, unique }, Value::Array(values)) => {
+                if values.len() < *min_items {
+                    violations.push(Violation::new(pointer, format!("at least {min_items} items"), value));
+                }
+                if let Some(max_items) = max_items && values.len() > *max_items {
+                    violations.push(Violation::new(pointer, format!("at most {max_items} items"), value));
+                }
+                if *unique && values.iter().enumerate().any(|(i, item)| values[..i].contains(item)) {
+                    violations.push(Violation::new(pointer, "unique items", value));
+                }
+                for (i, item) in values.iter().enumerate() {
+                    items.validate_at(&format!("{pointer}/{i}"), item, violations);
+                }
+            }
+            (JsonType::Object(members), Value::Object(object)) => {
+                for member in members {
+                    let member_pointer = format!("{pointer}/{}", json_pointer_token(&member.key));
+                    match object.get(&member.key) {
+                        Some(member_value) => member.value.validate_at(&member_pointer, member_value, violations),
+                        None if member.required => violations.push(Violation {
+                            pointer: member_pointer,
+                            expected: member.value.to_regex(),
+                            found: "nothing".to_string(),
+                        }),
+                        None => {}
+                    }
+                }
+                for (key, member_value) in object {
+                    if !members.iter().any(|member| member.key == *key) {
+                        violations.push(Violation::new(&format!("{pointer}/{}", json_pointer_token(key)), "no such member", member_value));
+                    }
+                }
+            }
+            (JsonType::Union(alternatives), value) => {
+                let alternative_violations = alternatives.iter().map(|alternative| alternative.validate(value)).collect::<Vec<_>>();
+                let Some(fewest) = alternative_violations.iter().map(Vec::len).min() else {
+                    violations.push(Violation::new(pointer, "no value at all", value));
+                    return;
+                };
+                if fewest == 0 {
+                    return;
+                }
+
+                // report the closest alternatives. If they each have a single violation, report the location where most
+                // of them differ from the value, merging what they expect there.
+                let closest = alternative_violations.into_iter().filter(|violations| violations.len() == fewest).collect::<Vec<_>>();
+                if fewest == 1 {
+                    let mut by_pointer: Vec<(&str, Vec<&Violation>)> = Vec::new();
+                    for violation in closest.iter().map(|violations| &violations[0]) {
+                        match by_pointer.iter_mut().find(|(pointer, _)| *pointer == violation.pointer) {
+                            Some((_, group)) => group.push(violation),
+                            None => by_pointer.push((&violation.pointer, vec![violation])),
+                        }
+                    }
+                    let (_, group) = by_pointer.iter().rev().max_by_key(|(_, group)| group.len()).unwrap();
+
+                    let mut expected = Vec::new();
+                    for violation in group {
+                        if !expected.contains(&violation.expected) {
+                            expected.push(violation.expected.clone());
+                        }
+                    }
+                    violations.push(Violation {
+                        pointer: format!("{pointer}{}", group[0].pointer),
+                        expected: expected.join(" or "),
+                        found: group[0].found.clone(),
+                    });
+                } else {
+                    violations.extend(closest[0].iter().map(|violation| Violation {
+                        pointer: format!("{pointer}{}", violation.pointer),
+                        ..violation.clone()
+                    }));
+                }
+            }
+            (json_type, value) => violations.push(Violation::new(pointer, json_type.type_name(), value)),
+        }
+    }
+
+    /// A short description of the kind of value this type accepts.
+    fn type_name(&self) -> &'static str {
+        match self {
+            JsonType::Null => "null",
+            JsonType::Boolean => "a boolean",
+            JsonType::Integer { .. } => "an integer",
+            JsonType::Number { .. } => "a number",
+            JsonType::String { .. } => "a string",
+            JsonType::Enum(_) => "one of the options",
+            JsonType::Array { .. } => "an array",
+            JsonType::Object(_) => "an object",
+            JsonType::Union(_) => "one of the alternatives",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn escape_regex_escapes_every_metacharacter() {
+        assert_eq!(escape_regex("P.J. (Jr.) a+b?"), r"P\.J\. \(Jr\.\) a\+b\?");
+        assert_eq!(escape_regex("Łukasz 大谷"), "Łukasz 大谷");
+    }
+
+    #[test]
+    fn json_string_literal_regex_handles_json_escapes() {
+        assert_eq!(json_string_literal_regex(r#"a"b"#, false), r#"a\\"b"#);
+        assert_eq!(json_string_literal_regex("é", true), r"(é|\\u00[eE]9)");
+        assert_eq!(json_string_literal_regex("😀", true), r"(😀|\\u[dD]83[dD]\\u[dD][eE]00)");
+    }
+
+    /// Asserts that `regex` matches exactly the numbers in `range` among those in `candidates`.
+    fn assert_matches_exactly(regex: &str, candidates: impl Iterator<Item = String>, in_range: impl Fn(&str) -> bool) {
+        let regex = regex::Regex::new(&format!("^({regex})$")).unwrap();
+        for candidate in candidates {
+            assert_eq!(regex.is_match(&candidate), in_range(&candidate), "{candidate} against {regex}");
+        }
+    }
+
+    #[test]
+    fn integer_range_regex_is_exact() {
+        for (min, max) in [(0, 0), (0, 9), (1, 9), (0, 999), (1, 999999), (7, 1234), (-30, 130), (-999, -10), (38, 41)] {
+            assert_matches_exactly(
+                &integer_range_regex(min, max),
+                (-1500..=1500).map(|n: i64| n.to_string()).chain(["01".to_string(), "-0".to_string()]),
+                |candidate| candidate.parse::<i64>().is_ok_and(|n| n.to_string() == candidate && (min..=max).contains(&n)),
+            );
+        }
+    }
+
+    #[test]
+    fn half_bounded_integer_regexes_are_exact() {
+        for bound in [0, 1, 7, 10, 38, 130, -1, -10, -38, -999] {
+            let candidates = || (-1500..=1500).map(|n: i64| n.to_string()).chain(["01", "-0", "12345", "-12345"].map(String::from));
+            let value = |candidate: &str| candidate.parse::<i64>().ok().filter(|n| n.to_string() == candidate);
+            assert_matches_exactly(&integer_at_least_regex(bound), candidates(), |candidate| value(candidate).is_some_and(|n| n >= bound));
+            assert_matches_exactly(&integer_at_most_regex(bound), candidates(), |candidate| value(candidate).is_some_and(|n| n <= bound));
+        }
+    }
+
+    #[test]
+    fn integer_range_regex_is_minimal() {
+        assert_eq!(integer_range_regex(0, 999), r"\d|[1-9]\d{1,2}");
+        assert_eq!(integer_range_regex(1, 999999), r"[1-9]\d{0,5}");
+        assert_eq!(integer_range_regex(-30, 130), r"-([1-9]|[12]\d|30)|\d|[1-9]\d|1[0-2]\d|130");
+    }
+
+    #[test]
+    fn number_range_regex_is_exact() {
+        for (min, max) in [(0, 10), (-30, 130), (-5, -2)] {
+            assert_matches_exactly(
+                &number_range_regex(min, max, 2),
+                (-1500..=1500).flat_map(|n: i64| [format!("{}", n as f64 / 10.0), format!("{:.2}", n as f64 / 100.0)]),
+                |candidate| candidate.parse::<f64>().is_ok_and(|n| (min as f64..=max as f64).contains(&n)),
+            );
+        }
+    }
+
+    /// Asserts that the regex for `json_type` in the given style matches exactly the lines in `matching` and none in
+    /// `not_matching`.
+    fn assert_regex_matches(json_type: &JsonType, style: RegexStyle, matching: &[&str], not_matching: &[&str]) {
+        let regex = regex::Regex::new(&format!("^({})$", json_type.to_regex_with_style(style))).unwrap();
+        for line in matching {
+            assert!(regex.is_match(line), "{line} should match {regex}");
+        }
+        for line in not_matching {
+            assert!(!regex.is_match(line), "{line} should not match {regex}");
+        }
+    }
+
+    #[test]
+    fn json_object_with_optional_members_to_regex() {
+        let object = JsonType::object(vec![
+            JsonType::optional_key_value("a", JsonType::integer()),
+            JsonType::key_value("b", JsonType::boolean()),
+            JsonType::optional_key_value("c", JsonType::nullable(JsonType::string())),
+        ]);
+        assert_regex_matches(
+            &object,
+            RegexStyle::Canonical,
+            &[r#"{ "b": true }"#, r#"{ "a": 1, "b": false }"#, r#"{ "b": true, "c": null }"#, r#"{ "a": 1, "b": true, "c": "x" }"#],
+            &[r#"{ "a": 1 }"#, r#"{ "b": true, "a": 1 }"#, r#"{ , "b": true }"#, "{}"],
+        );
+    }
+
+    #[test]
+    fn json_object_with_only_optional_members_to_regex() {
+        let object = JsonType::object(vec![
+            JsonType::optional_key_value("a", JsonType::integer()),
+            JsonType::optional_key_value("b", JsonType::boolean()),
+        ]);
+        assert_regex_matches(
+            &object,
+            RegexStyle::Canonical,
+            &["{}", r#"{ "a": 1 }"#, r#"{ "b": true }"#, r#"{ "a": 1, "b": true }"#],
+            &[r#"{ "b": true, "a": 1 }"#, r#"{ , "b": true }"#],
+        );
+    }
+
+    #[test]
+    fn json_enum_to_regex() {
+        assert_eq!(JsonType::string_enum(["Pop Out", "Sac Fly (Rare)"]).to_regex(), r#""(Pop Out|Sac Fly \(Rare\))""#);
+        assert_eq!(JsonType::integer_with_options(vec![1, 2]).to_regex(), "(1|2)");
+        assert_eq!(JsonType::boolean_with_options(vec![true]).to_regex(), "(true)");
+        assert_regex_matches(
+            &JsonType::enumeration(vec![json!(1), json!("one"), json!(null)]),
+            RegexStyle::Canonical,
+            &["1", r#""one""#, "null"],
+            &["one", "2"],
+        );
+    }
+
+    #[test]
+    fn json_object_to_json_schema() {
+        let object = JsonType::object(vec![
+            JsonType::key_value("type", JsonType::string_enum(["Single", "Double"])),
+            JsonType::key_value("number", JsonType::integer_range(1, 20)),
+            JsonType::optional_key_value("runner", JsonType::nullable(JsonType::string_with_regex("[a-z]+"))),
+        ]);
+        assert_eq!(object.to_json_schema(), json!({
+            "type": "object",
+            "properties": {
+                "type": { "enum": ["Single", "Double"] },
+                "number": { "type": "integer", "minimum": 1, "maximum": 20 },
+                "runner": { "anyOf": [{ "type": "string", "pattern": "^([a-z]+)$" }, { "type": "null" }] },
+            },
+            "required": ["type", "number"],
+            "additionalProperties": false,
+        }));
+    }
+
+    #[test]
+    fn json_schema_to_json_type() {
+        let json_type = JsonType::from_json_schema(&json!({
+            "title": "Box score line",
+            "type": "object",
+            "properties": {
+                "team": { "type": "string", "enum": ["home", "away"] },
+                "runs": { "type": "integer", "minimum": 0, "maximum": 30 },
+                "pitchers": { "type": "array", "items": { "type": "string", "pattern": "^[A-Z][a-z]+$" }, "minItems": 1 },
+                "note": { "anyOf": [{ "type": "string", "pattern": "^[a-z ]*$" }, { "type": "null" }] },
+            },
+            "required": ["team", "runs", "pitchers"],
+            "additionalProperties": false,
+        })).unwrap();
+
+        assert_regex_matches(
+            &json_type,
+            RegexStyle::Canonical,
+            &[
+                r#"{ "team": "home", "runs": 3, "pitchers": ["Greene"] }"#,
+                r#"{ "team": "away", "runs": 30, "pitchers": ["Greene", "Diaz"], "note": null }"#,
+            ],
+            &[
+                r#"{ "runs": 3, "team": "home", "pitchers": ["Greene"] }"#,
+                r#"{ "team": "home", "runs": 31, "pitchers": ["Greene"] }"#,
+                r#"{ "team": "home", "runs": 3, "pitchers": [] }"#,
+            ],
+        );
+        assert_regex_matches(
+            &JsonType::from_json_schema(&json!({ "type": "integer", "maximum": -2 })).unwrap(),
+            RegexStyle::Canonical,
+            &["-2", "-100"],
+            &["-1", "0", "7"],
+        );
+    }
+
+    #[test]
+    fn json_schema_round_trips_through_json_type() {
+        let object = JsonType::object(vec![
+            JsonType::key_value("type", JsonType::string_enum(["Single", "Double"])),
+            JsonType::key_value("number", JsonType::integer_range(1, 20)),
+            JsonType::optional_key_value("runner", JsonType::nullable(JsonType::string_with_regex("[a-z]+"))),
+        ]);
+
+        assert_regex_matches(
+            &JsonType::from_json_schema(&object.to_json_schema()).unwrap(),
+            RegexStyle::Canonical,
+            &[r#"{ "type": "Single", "number": 20, "runner": "abc" }"#, r#"{ "type": "Double", "number": 1 }"#],
+            &[r#"{ "type": "Triple", "number": 1 }"#, r#"{ "type": "Single", "number": 21 }"#],
+        );
+    }
+
+    #[test]
+    fn json_schema_with_unsupported_keywords_is_an_error() {
+        let error = |schema| JsonType::from_json_schema(&schema).unwrap_err().to_string();
+
+        assert_eq!(
+            error(json!({ "type": "object", "properties": { "name": { "type": "string", "format": "email" } } })),
+            r#"/properties/name/format: unsupported keyword "format""#,
+        );
+        assert_eq!(
+            error(json!({ "type": "array", "items": { "type": "string", "pattern": "[a-z]+" } })),
+            r#"/items/pattern: expected a pattern anchored with ^ and $, found "[a-z]+""#,
+        );
+        assert_eq!(error(json!({ "type": "integer", "minimum": 0.5 })), "/minimum: expected an integer, found 0.5");
+        assert_eq!(
+            error(json!({ "type": "array", "items": { "type": "integer" }, "minItems": 3, "maxItems": 1 })),
+            "/: expected item counts with minItems <= maxItems, found 3 and 1",
+        );
+        assert_eq!(
+            error(json!({ "type": "string", "enum": ["a", 1] })),
+            r#"/type: type "string" does not match the values allowed by enum"#,
+        );
+        assert_eq!(error(json!({ "$ref": "#/definitions/play" })), "/: expected a type, enum, const or anyOf");
+    }
+
+    #[test]
+    fn json_array_with_constraints_to_regex() {
+        assert_regex_matches(
+            &JsonType::array_with_constraints(JsonType::integer(), 1, Some(3), true),
+            RegexStyle::Canonical,
+            &["[1]", "[1, 2]", "[1, 2, 3]"],
+            &["[]", "[1, 2, 3, 4]", "[1,2]"],
+        );
+        assert_regex_matches(&JsonType::array_with_constraints(JsonType::integer(), 0, Some(1), false), RegexStyle::Canonical, &["[]", "[1]"], &["[1, 2]"]);
+        assert_regex_matches(&JsonType::array_with_constraints(JsonType::integer(), 2, None, false), RegexStyle::Canonical, &["[1, 2]", "[1, 2, 3]"], &["[]", "[1]"]);
+        assert_regex_matches(&JsonType::array_with_constraints(JsonType::integer(), 0, Some(0), false), RegexStyle::Canonical, &["[]"], &["[1]"]);
+    }
+
+    #[test]
+    fn json_array_with_constraints_to_json_schema() {
+        assert_eq!(
+            JsonType::array_with_constraints(JsonType::string(), 1, Some(4), true).to_json_schema(),
+            json!({ "type": "array", "items": { "type": "string", "pattern": "^([^\"]*)$" }, "minItems": 1, "maxItems": 4, "uniqueItems": true }),
+        );
+    }
+
+    #[test]
+    fn json_object_to_regex_with_style() {
+        let object = JsonType::object(vec![
+            JsonType::key_value("a", JsonType::integer()),
+            JsonType::optional_key_value("b", JsonType::array(JsonType::boolean())),
+        ]);
+
+        assert_regex_matches(&object, RegexStyle::Canonical, &[r#"{ "a": 1, "b": [true, false] }"#], &[r#"{"a":1}"#]);
+        assert_regex_matches(
+            &object,
+            RegexStyle::FlexibleWhitespace,
+            &[r#"{"a":1,"b":[true,false]}"#, r#"{ "a" : 1 }"#, r#"{ "a": 1, "b": [ ] }"#],
+            &[r#"{"b":[],"a":1}"#],
+        );
+        assert_regex_matches(
+            &object,
+            RegexStyle::AnyKeyOrder { max_members: 2 },
+            &[r#"{"b":[],"a":1}"#, r#"{ "a": 1 }"#],
+            &[r#"{"b":[]}"#],
+        );
+        assert_regex_matches(&object, RegexStyle::AnyKeyOrder { max_members: 1 }, &[r#"{"a":1,"b":[]}"#], &[r#"{"b":[],"a":1}"#]);
+    }
+
+    #[test]
+    fn validate_reports_json_pointers() {
+        let movement = |runner: &str, end_base: &str| JsonType::object(vec![
+            JsonType::key_value("runner", JsonType::string_enum([runner])),
+            JsonType::key_value("end_base", JsonType::string_enum([end_base])),
+        ]);
+        let line = JsonType::object(vec![
+            JsonType::key_value("inning", JsonType::integer_range(1, 20)),
+            JsonType::key_value("fielders", JsonType::array_with_constraints(JsonType::string(), 1, Some(2), true)),
+            JsonType::key_value("movements", JsonType::array(JsonType::union(vec![movement("A", "1"), movement("A", "2"), movement("B", "3")]))),
+        ]);
+
+        assert_eq!(line.validate(&json!({ "inning": 3, "fielders": ["X"], "movements": [{ "runner": "A", "end_base": "2" }] })), vec![]);
+
+        let violations = line.validate(&json!({
+            "inning": 0,
+            "fielders": ["X", "X", "Y"],
+            "movements": [{ "runner": "B", "end_base": "3" }, { "runner": "A", "end_base": "3" }],
+            "extra": true,
+        }));
+        assert_eq!(violations.iter().map(|violation| violation.pointer.as_str()).collect::<Vec<_>>(), vec![
+            "/inning",
+            "/fielders",
+            "/fielders",
+            "/movements/1/end_base",
+            "/extra",
+        ]);
+        assert_eq!(violations[3].expected, r#"one of "(1)" or one of "(2)""#);
+        assert_eq!(violations[3].found, r#""3""#);
+    }
+
+    #[test]
+    fn validate_reports_missing_members_and_wrong_types() {
+        let object = JsonType::object(vec![
+            JsonType::key_value("a/b", JsonType::boolean()),
+            JsonType::key_value("c", JsonType::string_with_regex("[a-z]+")),
+            JsonType::optional_key_value("d", JsonType::null()),
+        ]);
+
+        let violations = object.validate(&json!({ "c": 1 }));
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].pointer, "/a~1b");
+        assert_eq!(violations[0].found, "nothing");
+        assert_eq!(violations[1].to_string(), "/c: expected a string, found 1");
+        assert_eq!(object.validate(&json!({ "a/b": true, "c": "Z" }))[0].expected, r#"a string matching "([a-z]+)""#);
+    }
+
+    #[test]
+    fn validate_compiles_large_patterns_and_reports_broken_ones() {
+        let large = JsonType::string_with_regex(r"\w{300}");
+        assert!(regex::Regex::new(&format!("^({})$", large.to_regex())).is_err());
+        assert!(large.validate(&json!("a".repeat(300))).is_empty());
+
+        let violations = JsonType::string_with_regex("(").validate(&json!("a"));
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].expected.contains("does not compile"), "{}", violations[0].expected);
+    }
+
+    #[test]
+    fn regex_dialects_rewrite_digits_groups_and_classes() {
+        let regex = format!(r"(\d|[1-9]\d{{1,2}})-({})", unicode_text_regex("&'- "));
+
+        assert_eq!(RegexDialect::PythonRe.translate(&regex), r"(?:[0-9]|[1-9][0-9]{1,2})-(?:(?:[^\W\d_]|[\&'\- ])+)");
+        assert_eq!(RegexDialect::RustRegex.translate(&regex), RegexDialect::PythonRe.translate(&regex));
+        assert_eq!(
+            RegexDialect::Interegular.translate(&regex),
+            format!(r"(?:[0-9]|[1-9][0-9]{{1,2}})-(?:(?:[{EXPLICIT_LETTER_RANGES}]|[&'\- ])+)"),
+        );
+
+        let rust_regex = regex::Regex::new(&format!("^{}$", RegexDialect::RustRegex.translate(&regex))).unwrap();
+        assert!(rust_regex.is_match("42-Jean-Luc d'Arnaud"));
+        assert!(!rust_regex.is_match("٤٢-Jean"));
+
+        let array = JsonType::array(JsonType::integer());
+        assert_eq!(array.to_regex_with(RegexStyle::Canonical, Some(RegexDialect::PythonRe)), r"\[(?:(?:0|-?[1-9][0-9]*)(?:, (?:0|-?[1-9][0-9]*))*)?\]");
+        assert_eq!(array.to_regex_with(RegexStyle::Canonical, None), array.to_regex());
+    }
+
     #[test]
     fn json_array_to_regex() {
         let array = JsonType::array(JsonType::integer());
-        assert_eq!(array.to_regex(), r"\[(([1-9]\d+)(, ([1-9]\d+))*)?\]");
+        assert_eq!(array.to_regex(), r"\[((0|-?[1-9]\d*)(, (0|-?[1-9]\d*))*)?\]");
     }
 }
@@ -0,0 +1,536 @@
+use super::{Base, Context, FormatProfile, Game, Inning, Movement, Play, PlayBuilder, PlayField, PlayKind, PlayType, Player, Team, Weather};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt, fs, path::Path};
+use strum::IntoEnumIterator;
+
+/// The position of a player in the format, for each position abbreviation used by the live feed.
+const POSITION_ABBREVIATIONS: [(&str, &str); 19] = [
+    ("P", "PITCHER"),
+    ("C", "CATCHER"),
+    ("1B", "FIRST_BASE"),
+    ("2B", "SECOND_BASE"),
+    ("3B", "THIRD_BASE"),
+    ("SS", "SHORTSTOP"),
+    ("LF", "LEFT_FIELD"),
+    ("CF", "CENTER_FIELD"),
+    ("RF", "RIGHT_FIELD"),
+    ("DH", "DESIGNATED_HITTER"),
+    ("PH", "PINCH_HITTER"),
+    ("PR", "PINCH_RUNNER"),
+    ("TWP", "TWO_WAY_PLAYER"),
+    ("OF", "OUTFIELD"),
+    ("IF", "INFIELD"),
+    ("UT", "UTILITY"),
+    ("UTIL", "UTILITY"),
+    ("RP", "RELIEF_PITCHER"),
+    ("SP", "STARTING_PITCHER"),
+];
+
+/// The play type names of the format, for each event name of the live feed that differs from them.
+const EVENT_ALIASES: [(&str, &str); 2] = [
+    ("Grounded Into DP", "Grounded Into Double Play"),
+    ("Batter Interference", "Batter Out"),
+];
+
+/// An error in a live feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveFeedError {
+    pub message: String,
+}
+
+impl LiveFeedError {
+    fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for LiveFeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+// The parts of the MLB Stats API `feed/live` document that the conversion reads.
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Feed {
+    game_pk: u32,
+    game_data: GameData,
+    live_data: LiveData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GameData {
+    datetime: DateTime,
+    venue: Venue,
+    weather: FeedWeather,
+    teams: HomeAway<FeedTeam>,
+    players: HashMap<String, Person>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DateTime {
+    official_date: String,
+}
+
+#[derive(Deserialize)]
+struct Venue {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct FeedWeather {
+    condition: String,
+    /// The temperature in degrees Fahrenheit, e.g. `"78"`.
+    temp: String,
+    /// The wind speed and direction, e.g. `"5 mph, Out To CF"`.
+    wind: String,
+}
+
+#[derive(Deserialize)]
+struct HomeAway<T> {
+    home: T,
+    away: T,
+}
+
+#[derive(Deserialize)]
+struct FeedTeam {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Person {
+    id: u32,
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct PersonId {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveData {
+    plays: FeedPlays,
+    boxscore: Boxscore,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedPlays {
+    all_plays: Vec<FeedPlay>,
+}
+
+#[derive(Deserialize)]
+struct Boxscore {
+    teams: HomeAway<BoxscoreTeam>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BoxscoreTeam {
+    /// The team's players, keyed by `ID` followed by their id, in the order the feed lists them.
+    players: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    batting_order: Vec<u32>,
+}
+
+#[derive(Deserialize)]
+struct BoxscorePlayer {
+    person: Person,
+    position: Position,
+}
+
+#[derive(Deserialize)]
+struct Position {
+    abbreviation: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedPlay {
+    result: PlayResult,
+    about: About,
+    matchup: Matchup,
+    #[serde(default)]
+    runners: Vec<Runner>,
+    #[serde(default)]
+    play_events: Vec<PlayEvent>,
+}
+
+#[derive(Deserialize)]
+struct PlayResult {
+    /// The event that ended the at-bat, missing while it is in progress.
+    #[serde(default)]
+    event: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct About {
+    inning: u32,
+    is_top_inning: bool,
+}
+
+#[derive(Deserialize)]
+struct Matchup {
+    batter: Person,
+    pitcher: Person,
+}
+
+#[derive(Deserialize)]
+struct Runner {
+    movement: RunnerMovement,
+    details: RunnerDetails,
+    #[serde(default)]
+    credits: Vec<Credit>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunnerMovement {
+    /// The base the runner starts this movement from, `None` for the batter.
+    start: Option<String>,
+    /// The base the runner ends this movement at, `"score"` for a run, and `None` for a runner who is out.
+    end: Option<String>,
+    #[serde(default)]
+    is_out: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunnerDetails {
+    runner: Person,
+    /// The index in the at-bat's `playEvents` of the event the movement happened during.
+    play_index: usize,
+}
+
+#[derive(Deserialize)]
+struct Credit {
+    player: PersonId,
+    position: Position,
+}
+
+#[derive(Deserialize)]
+struct PlayEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    details: PlayEventDetails,
+}
+
+#[derive(Deserialize, Default)]
+struct PlayEventDetails {
+    #[serde(default)]
+    event: Option<String>,
+}
+
+/// The base a live feed names `base`, e.g. `"2B"`.
+fn feed_base(base: &str) -> Result<Base, LiveFeedError> {
+    match base {
+        "1B" => Ok(Base::First),
+        "2B" => Ok(Base::Second),
+        "3B" => Ok(Base::Third),
+        "4B" | "score" | "Home" => Ok(Base::Home),
+        base => Err(LiveFeedError::new(format!("unknown base {base:?}"))),
+    }
+}
+
+/// The play type of a live feed event, and the base at the end of its name for events such as `"Stolen Base 2B"`.
+fn event_play_type(event: &str) -> Option<(PlayType, Option<Base>)> {
+    let (name, base) = match event.rsplit_once(' ') {
+        Some((name, base)) if matches!(base, "1B" | "2B" | "3B" | "Home") => (name, feed_base(base).ok()),
+        _ => (event, None),
+    };
+    let name = EVENT_ALIASES.iter().find(|(alias, _)| *alias == name).map_or(name, |(_, name)| *name);
+
+    PlayType::iter().find(|play_type| play_type.name() == name).map(|play_type| (play_type, base))
+}
+
+/// The movement of a runner. The batter starts at home, and a runner who is out ends at home, as in the files the
+/// format was first written from.
+fn movement(runner: &Runner) -> Result<Movement, LiveFeedError> {
+    let RunnerMovement { start, end, is_out } = &runner.movement;
+
+    Ok(Movement {
+        runner: runner.details.runner.full_name.clone(),
+        start_base: start.as_deref().map_or(Ok(Base::Home), feed_base)?,
+        end_base: end.as_deref().map_or(Ok(Base::Home), feed_base)?,
+        is_out: is_out.unwrap_or(false),
+    })
+}
+
+/// The leading number of `text`, e.g. `5` for `"5 mph, Out To CF"`, or 0 if it has none.
+fn leading_number(text: &str) -> u32 {
+    let digits = text.trim_start().chars().take_while(char::is_ascii_digit).collect::<String>();
+    digits.parse().unwrap_or(0)
+}
+
+/// The roster of a team, with the position of each player in the format.
+fn team(id: u32, boxscore_team: &BoxscoreTeam) -> Result<Team, LiveFeedError> {
+    let players = boxscore_team.players.values().map(|player| {
+        let player = serde_json::from_value::<BoxscorePlayer>(player.clone())
+            .map_err(|e| LiveFeedError::new(format!("invalid live feed: invalid boxscore player: {e}")))?;
+        let abbreviation = player.position.abbreviation;
+        let position = POSITION_ABBREVIATIONS.iter().find(|(other, _)| *other == abbreviation).ok_or_else(|| {
+            LiveFeedError::new(format!("unknown position {abbreviation:?} for {}", player.person.full_name))
+        })?.1;
+
+        Ok(Player { position: position.to_string(), name: player.person.full_name })
+    }).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Team { id, players })
+}
+
+/// The starting catcher of a team: the first player of its batting order who plays catcher.
+fn starting_catcher(boxscore_team: &BoxscoreTeam) -> Option<String> {
+    boxscore_team.batting_order.iter().find_map(|id| {
+        let player = serde_json::from_value::<BoxscorePlayer>(boxscore_team.players.get(&format!("ID{id}"))?.clone()).ok()?;
+        (player.position.abbreviation == "C").then_some(player.person.full_name)
+    })
+}
+
+/// Converts the plays of a live feed, keeping track of each team's catcher, who is only named by the feed when they
+/// are credited on a play.
+struct PlayConverter<'a> {
+    /// The profile whose play types events the format has none for, such as `"Fan Interference"`, are converted to.
+    profile: &'a FormatProfile,
+    names: HashMap<u32, &'a str>,
+    /// The current catcher of the home and away teams.
+    catchers: HomeAway<Option<String>>,
+}
+
+impl PlayConverter<'_> {
+    /// The name of the player with the given id.
+    fn name(&self, id: u32) -> Result<String, LiveFeedError> {
+        self.names.get(&id).map(|name| name.to_string()).ok_or_else(|| LiveFeedError::new(format!("unknown player id {id}")))
+    }
+
+    /// The plays of an at-bat: one for each action during it that moved runners, such as a stolen base, followed by one
+    /// for the at-bat itself. Movements during other events belong to the at-bat's play.
+    fn convert(&mut self, feed_play: &FeedPlay) -> Result<Vec<Play>, LiveFeedError> {
+        let Some(event) = &feed_play.result.event else {
+            return Ok(Vec::new());
+        };
+        let (play_kind, base) = match event_play_type(event) {
+            Some((play_type, base)) => (PlayKind::from(play_type), base),
+            None => {
+                let play_kind = self.profile.play_kind(event).cloned().ok_or_else(|| {
+                    LiveFeedError::new(format!("unknown event {event:?}: a format profile must declare it as a play type"))
+                })?;
+                (play_kind, None)
+            }
+        };
+
+        let mut action_runners = Vec::<(usize, PlayType, Option<Base>, Vec<&Runner>)>::new();
+        let mut at_bat_runners = Vec::new();
+        for runner in &feed_play.runners {
+            let play_index = runner.details.play_index;
+            let action = feed_play.play_events.get(play_index)
+                .filter(|play_event| play_event.event_type == "action")
+                .and_then(|play_event| play_event.details.event.as_deref().and_then(event_play_type));
+            match action {
+                Some((play_type, base)) => match action_runners.iter_mut().find(|(index, ..)| *index == play_index) {
+                    Some((.., runners)) => runners.push(runner),
+                    None => action_runners.push((play_index, play_type, base, vec![runner])),
+                },
+                None => at_bat_runners.push(runner),
+            }
+        }
+        action_runners.sort_by_key(|(index, ..)| *index);
+
+        let mut plays = Vec::new();
+        for (_, play_type, base, runners) in action_runners {
+            plays.push(self.play(feed_play, PlayKind::from(play_type), base, &runners)?);
+        }
+        plays.push(self.play(feed_play, play_kind, base, &at_bat_runners)?);
+
+        Ok(plays)
+    }
+
+    /// A play of the given type, with the fields its type has taken from the at-bat and the runners that moved.
+    fn play(&mut self, feed_play: &FeedPlay, play_kind: PlayKind, base: Option<Base>, runners: &[&Runner]) -> Result<Play, LiveFeedError> {
+        let inning = Inning { number: feed_play.about.inning, top: feed_play.about.is_top_inning };
+        let batter = &feed_play.matchup.batter.full_name;
+        let movements = runners.iter().map(|runner| movement(runner)).collect::<Result<Vec<_>, _>>()?;
+
+        let mut fielders = Vec::new();
+        for credit in runners.iter().flat_map(|runner| &runner.credits) {
+            let name = self.name(credit.player.id)?;
+            if credit.position.abbreviation == "C" {
+                let catcher = if inning.top { &mut self.catchers.home } else { &mut self.catchers.away };
+                *catcher = Some(name.clone());
+            }
+            if !fielders.contains(&name) {
+                fielders.push(name);
+            }
+        }
+        let catcher = if inning.top { &self.catchers.home } else { &self.catchers.away };
+
+        let mut play_builder = PlayBuilder::new();
+        play_builder.set_inning(inning);
+        let fields = play_kind.information.iter().copied().chain(play_kind.movements.then_some(PlayField::Movements)).collect::<Vec<_>>();
+        play_builder.set_play_kind(play_kind);
+        for field in fields {
+            match field {
+                PlayField::Base => play_builder.base = base,
+                PlayField::Batter => play_builder.set_batter(batter.clone()),
+                PlayField::Pitcher => play_builder.set_pitcher(feed_play.matchup.pitcher.full_name.clone()),
+                PlayField::Catcher => play_builder.catcher = catcher.clone(),
+                PlayField::Fielders => play_builder.fielders = (!fielders.is_empty()).then(|| fielders.clone()),
+                PlayField::Runner => {
+                    play_builder.runner = movements.iter().find(|movement| movement.runner != *batter)
+                        .or(movements.first())
+                        .map(|movement| movement.runner.clone());
+                }
+                PlayField::ScoringRunner => {
+                    play_builder.scoring_runner = movements.iter()
+                        .find(|movement| movement.end_base == Base::Home && movement.start_base != Base::Home && !movement.is_out)
+                        .map(|movement| movement.runner.clone());
+                }
+                PlayField::Movements => play_builder.set_movements(movements.clone()),
+            }
+        }
+
+        play_builder.build().map_err(|e| LiveFeedError::new(format!(
+            "invalid play in {} of inning {}: {e}",
+            if inning.top { "top" } else { "bottom" },
+            inning.number,
+        )))
+    }
+}
+
+impl Game {
+    /// Converts a game saved from the MLB Stats API's `feed/live` endpoint: its venue, weather and rosters become the
+    /// context, and its at-bats and the actions during them that moved runners, such as stolen bases, become plays.
+    /// At-bats still in progress are left out, and an at-bat ending in an event the format has no play type for, such
+    /// as fan interference, is an error: convert with `from_live_feed_with_profile` to keep it.
+    pub fn from_live_feed(json: &str) -> Result<Self, LiveFeedError> {
+        Self::from_live_feed_with_profile(json, &FormatProfile::mlb())
+    }
+
+    /// Converts a live feed as `from_live_feed` does, with at-bats ending in an event the format has no play type for
+    /// converted to the play type of `profile` named after the event, so that the game can be read back by a parser
+    /// with the same profile. Events `profile` does not declare either are an error.
+    pub fn from_live_feed_with_profile(json: &str, profile: &FormatProfile) -> Result<Self, LiveFeedError> {
+        let feed = serde_json::from_str::<Feed>(json).map_err(|e| LiveFeedError::new(format!("invalid live feed: {e}")))?;
+        let GameData { datetime, venue, weather, teams, players } = &feed.game_data;
+        let boxscore = &feed.live_data.boxscore.teams;
+
+        let context = Context {
+            game_pk: feed.game_pk,
+            date: datetime.official_date.clone(),
+            venue_name: venue.name.clone(),
+            weather: Weather {
+                condition: weather.condition.clone(),
+                temperature: weather.temp.trim().parse()
+                    .map_err(|_| LiveFeedError::new(format!("invalid temperature {:?}", weather.temp)))?,
+                wind_speed: leading_number(&weather.wind),
+            },
+            home_team: team(teams.home.id, &boxscore.home)?,
+            away_team: team(teams.away.id, &boxscore.away)?,
+        };
+
+        let mut play_converter = PlayConverter {
+            profile,
+            names: players.values().map(|person| (person.id, person.full_name.as_str())).collect(),
+            catchers: HomeAway { home: starting_catcher(&boxscore.home), away: starting_catcher(&boxscore.away) },
+        };
+        let mut plays = Vec::new();
+        for feed_play in &feed.live_data.plays.all_plays {
+            plays.extend(play_converter.convert(feed_play)?);
+        }
+
+        Ok(Game { context, plays })
+    }
+
+    /// Converts the live feed saved in the file at `path`, as `from_live_feed` does.
+    pub fn from_live_feed_file(path: &Path) -> Result<Self, LiveFeedError> {
+        let json = fs::read_to_string(path).map_err(|e| LiveFeedError::new(format!("could not read {}: {e}", path.display())))?;
+        Self::from_live_feed(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    const FEED: &str = include_str!("../../test_data/live_feed/900001.json");
+    const CONVERTED: &str = include_str!("../../test_data/live_feed/900001.jsonl");
+    /// The first inning of game 748236 in the shape the API serves it, with every field the conversion ignores kept.
+    const FIRST_INNING_FEED: &str = include_str!("../../test_data/live_feed/748236.json");
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn live_feed_is_converted_to_the_format() {
+        let game = Game::from_live_feed(FEED).unwrap();
+
+        assert_eq!(game.to_jsonl(), CONVERTED);
+        assert_eq!(Parser::new(false).parse_str(CONVERTED).unwrap().remove(0).plays, game.plays);
+    }
+
+    #[test]
+    fn actions_during_at_bats_are_separate_plays() {
+        let game = Game::from_live_feed(FEED).unwrap();
+        let names = game.plays.iter().map(Play::name).collect::<Vec<_>>();
+
+        assert_eq!(&names[..4], ["Single", "Stolen Base", "Strikeout", "Wild Pitch"]);
+        assert_eq!(game.plays[1].base(), Some(Base::Second));
+        assert_eq!(game.plays[1].runner(), Some("Andy Adams"));
+        assert_eq!(game.plays[2].movements().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn api_feed_matches_the_game_file() {
+        let converted = Game::from_live_feed(FIRST_INNING_FEED).unwrap();
+        let game = Parser::new(false).parse_str(GAME).unwrap().remove(0);
+
+        assert_eq!(converted.plays, game.plays[..10]);
+        assert_eq!(
+            (converted.context.game_pk, &converted.context.date, &converted.context.venue_name, &converted.context.weather),
+            (game.context.game_pk, &game.context.date, &game.context.venue_name, &game.context.weather),
+        );
+        for (team, full_team) in [(&converted.context.home_team, &game.context.home_team), (&converted.context.away_team, &game.context.away_team)] {
+            assert_eq!(team.id, full_team.id);
+            assert!(team.players.iter().all(|player| full_team.players.contains(player)), "{:?}", team.players);
+        }
+    }
+
+    #[test]
+    fn unmapped_events_need_a_profile() {
+        let feed = FEED.replace("\"event\": \"Groundout\"", "\"event\": \"Fan Interference\"");
+        let error = Game::from_live_feed(&feed).unwrap_err();
+        assert_eq!(error.to_string(), r#"unknown event "Fan Interference": a format profile must declare it as a play type"#);
+
+        let mut profile = FormatProfile::mlb();
+        profile.play_types.push(PlayKind { name: "Fan Interference".to_string(), information: vec![PlayField::Batter, PlayField::Pitcher], movements: true });
+        let game = Game::from_live_feed_with_profile(&feed, &profile).unwrap();
+        let play = game.plays.iter().find(|play| play.name() == "Fan Interference").unwrap();
+
+        assert!(matches!(play, Play::Custom { .. }));
+        assert_eq!((play.batter().is_some(), play.pitcher().is_some(), play.fielders()), (true, true, None));
+        assert_eq!(play.movements().unwrap().len(), 1);
+        assert_eq!(game.plays.len(), Game::from_live_feed(FEED).unwrap().plays.len());
+        assert_eq!(Parser::with_profile(false, profile).parse_str(&game.to_jsonl()).unwrap().remove(0).plays, game.plays);
+    }
+
+    #[test]
+    fn event_names() {
+        assert_eq!(event_play_type("Grounded Into DP"), Some((PlayType::GroundedIntoDoublePlay, None)));
+        assert_eq!(event_play_type("Caught Stealing Home"), Some((PlayType::CaughtStealing, Some(Base::Home))));
+        assert_eq!(event_play_type("Pickoff Caught Stealing 2B"), Some((PlayType::PickoffCaughtStealing, Some(Base::Second))));
+        assert_eq!(event_play_type("Mound Visit"), None);
+    }
+
+    #[test]
+    fn invalid_live_feeds() {
+        let error = Game::from_live_feed(&FEED.replace("\"abbreviation\": \"CF\"", "\"abbreviation\": \"XX\"")).unwrap_err();
+        assert_eq!(error.to_string(), "unknown position \"XX\" for Cy Clark");
+    }
+}
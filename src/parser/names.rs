@@ -0,0 +1,157 @@
+use super::game::Context;
+use std::collections::HashMap;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// Folds a player name into a key that ignores case, accents and punctuation.
+///
+/// For example, `"José Suárez"` and `"Jose Suarez"` both fold to `"jose suarez"`, and `"P.J. Higgins"` and
+/// `"PJ Higgins"` both fold to `"pj higgins"`. Hyphens are treated as spaces, so `"Jean-Luc"` and `"Jean Luc"` also
+/// match.
+pub fn fold_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.nfkd().filter(|c| !is_combining_mark(*c)).flat_map(char::to_lowercase) {
+        // letters that do not decompose into a base letter and a combining mark
+        match c {
+            'ł' => folded.push('l'),
+            'ø' => folded.push('o'),
+            'đ' | 'ð' => folded.push('d'),
+            'ı' => folded.push('i'),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            '-' | '‐' | '–' => folded.push(' '),
+            c if c.is_alphanumeric() || c.is_whitespace() => folded.push(c),
+            _ => {}
+        }
+    }
+
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The result of resolving a name against a roster.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NameMatch {
+    /// The name is spelled exactly as it is on the roster.
+    Exact,
+    /// The name matched a single roster entry after folding or through an alias.
+    Canonical(String),
+    /// The name matched more than one roster entry after folding.
+    Ambiguous(Vec<String>),
+    /// The name matched no roster entry.
+    Unknown,
+}
+
+/// Resolves the spellings used by different sources to the spelling used on a game's roster.
+#[derive(Debug, Default)]
+pub struct NameResolver {
+    /// The roster names, exactly as they are spelled in the context.
+    roster: Vec<String>,
+    /// The roster names, keyed by their folded form.
+    folded_roster: HashMap<String, Vec<String>>,
+    /// The canonical roster spelling for each alias, keyed by the alias's folded form.
+    aliases: HashMap<String, String>,
+}
+
+impl NameResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the roster with the players of both teams in the given context.
+    pub fn set_roster(&mut self, context: &Context) {
+        self.roster.clear();
+        self.folded_roster.clear();
+
+        for player in context.home_team.players.iter().chain(context.away_team.players.iter()) {
+            if self.roster.contains(&player.name) {
+                continue;
+            }
+            self.roster.push(player.name.clone());
+            self.folded_roster.entry(fold_name(&player.name)).or_default().push(player.name.clone());
+        }
+    }
+
+    /// Registers `alias` as another spelling of the roster name `canonical`.
+    pub fn add_alias(&mut self, alias: &str, canonical: &str) {
+        self.aliases.insert(fold_name(alias), canonical.to_string());
+    }
+
+    /// Resolves the given name to its roster spelling.
+    pub fn resolve(&self, name: &str) -> NameMatch {
+        if self.roster.iter().any(|roster_name| roster_name == name) {
+            return NameMatch::Exact;
+        }
+
+        let folded = fold_name(name);
+        if let Some(canonical) = self.aliases.get(&folded) {
+            return NameMatch::Canonical(canonical.clone());
+        }
+
+        match self.folded_roster.get(&folded).map(Vec::as_slice) {
+            Some([canonical]) => NameMatch::Canonical(canonical.clone()),
+            Some(candidates) if !candidates.is_empty() => NameMatch::Ambiguous(candidates.to_vec()),
+            _ => NameMatch::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::game::{Player, Team, Weather};
+
+    fn context(home_names: &[&str], away_names: &[&str]) -> Context {
+        let team = |id, names: &[&str]| Team {
+            id,
+            players: names.iter().map(|name| Player { position: "PITCHER".to_string(), name: name.to_string() }).collect(),
+        };
+
+        Context {
+            game_pk: 1,
+            date: "2024-04-24".to_string(),
+            venue_name: "Test Stadium".to_string(),
+            weather: Weather { condition: "Sunny".to_string(), temperature: 70, wind_speed: 10 },
+            home_team: team(1, home_names),
+            away_team: team(2, away_names),
+        }
+    }
+
+    #[test]
+    fn fold_name_ignores_accents_case_and_punctuation() {
+        assert_eq!(fold_name("José Suárez"), "jose suarez");
+        assert_eq!(fold_name("P.J. Higgins"), fold_name("PJ Higgins"));
+        assert_eq!(fold_name("Travis d'Arnaud"), "travis darnaud");
+        assert_eq!(fold_name("Jean-Luc  Łukasz"), "jean luc lukasz");
+    }
+
+    #[test]
+    fn resolve_canonicalizes_variant_spellings() {
+        let mut resolver = NameResolver::new();
+        resolver.set_roster(&context(&["José Suarez", "P.J. Higgins"], &["Jane Doe"]));
+
+        assert_eq!(resolver.resolve("José Suarez"), NameMatch::Exact);
+        assert_eq!(resolver.resolve("Jose Suarez"), NameMatch::Canonical("José Suarez".to_string()));
+        assert_eq!(resolver.resolve("PJ Higgins"), NameMatch::Canonical("P.J. Higgins".to_string()));
+        assert_eq!(resolver.resolve("John Smith"), NameMatch::Unknown);
+    }
+
+    #[test]
+    fn resolve_uses_aliases() {
+        let mut resolver = NameResolver::new();
+        resolver.set_roster(&context(&["Phillip Higgins"], &[]));
+        resolver.add_alias("P.J. Higgins", "Phillip Higgins");
+
+        assert_eq!(resolver.resolve("PJ Higgins"), NameMatch::Canonical("Phillip Higgins".to_string()));
+    }
+
+    #[test]
+    fn resolve_reports_ambiguous_names() {
+        let mut resolver = NameResolver::new();
+        resolver.set_roster(&context(&["José Ramírez"], &["Jose Ramirez"]));
+
+        assert_eq!(
+            resolver.resolve("JOSE RAMIREZ"),
+            NameMatch::Ambiguous(vec!["José Ramírez".to_string(), "Jose Ramirez".to_string()]),
+        );
+    }
+}
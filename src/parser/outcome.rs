@@ -0,0 +1,149 @@
+use super::PlayType;
+
+/// How a plate appearance ended, as far as batting and pitching statistics are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Single,
+    Double,
+    Triple,
+    HomeRun,
+    Walk,
+    IntentionalWalk,
+    HitByPitch,
+    /// The batter reached first on catcher's interference.
+    CatcherInterference,
+    /// A strikeout, including a strikeout double play.
+    Strikeout,
+    /// A sacrifice fly, including a sacrifice fly double play.
+    SacFly,
+    /// A sacrifice bunt, including a sacrifice bunt double play.
+    SacBunt,
+    GroundedIntoDoublePlay,
+    /// The batter reached on a fielding error.
+    ReachedOnError,
+    /// The batter reached on a fielder's choice, whether or not a runner was put out.
+    FieldersChoice,
+    /// Any other out on the batter's plate appearance: an out on a ball in play, a force out, a double or triple play
+    /// other than a ground ball one, or batter interference.
+    Out,
+}
+
+impl Outcome {
+    /// Whether the plate appearance counts as an at bat: all of them but walks, hit by pitches, catcher's
+    /// interference and sacrifices.
+    pub fn is_at_bat(&self) -> bool {
+        !matches!(
+            self,
+            Self::Walk | Self::IntentionalWalk | Self::HitByPitch | Self::CatcherInterference | Self::SacFly | Self::SacBunt
+        )
+    }
+
+    pub fn is_hit(&self) -> bool {
+        self.total_bases() > 0
+    }
+
+    /// The number of bases of a hit, or 0 for any other outcome.
+    pub fn total_bases(&self) -> u32 {
+        match self {
+            Self::Single => 1,
+            Self::Double => 2,
+            Self::Triple => 3,
+            Self::HomeRun => 4,
+            _ => 0,
+        }
+    }
+
+    /// Whether the batter is credited with the runs that score on the plate appearance: on every outcome but a
+    /// strikeout, whose runs score on a wild pitch or an error, an error, and a ground ball double play.
+    pub fn credits_runs_batted_in(&self) -> bool {
+        !matches!(self, Self::Strikeout | Self::ReachedOnError | Self::GroundedIntoDoublePlay)
+    }
+
+    /// Whether the outcome is a walk, intentional or not.
+    pub fn is_walk(&self) -> bool {
+        matches!(self, Self::Walk | Self::IntentionalWalk)
+    }
+}
+
+impl PlayType {
+    /// The outcome of the plate appearance a play of this type ends, or `None` for plays that do not end one, such as
+    /// stolen bases, wild pitches and game advisories. Every play type with a `batter` field ends a plate appearance:
+    ///
+    /// | Play types | Outcome |
+    /// | --- | --- |
+    /// | Single, Double, Triple, Home Run | `Single`, `Double`, `Triple`, `HomeRun` |
+    /// | Walk, Intent Walk, Hit By Pitch | `Walk`, `IntentionalWalk`, `HitByPitch` |
+    /// | Catcher Interference | `CatcherInterference` |
+    /// | Strikeout, Strikeout Double Play | `Strikeout` |
+    /// | Sac Fly, Sac Fly Double Play | `SacFly` |
+    /// | Sac Bunt, Sac Bunt Double Play | `SacBunt` |
+    /// | Grounded Into Double Play | `GroundedIntoDoublePlay` |
+    /// | Field Error | `ReachedOnError` |
+    /// | Fielders Choice, Fielders Choice Out | `FieldersChoice` |
+    /// | Groundout, Lineout, Flyout, Pop Out and their bunt versions | `Out` |
+    /// | Forceout, Double Play, Triple Play | `Out` |
+    /// | Runner Double Play, Runner Triple Play, Batter Out | `Out` |
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self {
+            Self::Single => Some(Outcome::Single),
+            Self::Double => Some(Outcome::Double),
+            Self::Triple => Some(Outcome::Triple),
+            Self::HomeRun => Some(Outcome::HomeRun),
+            Self::Walk => Some(Outcome::Walk),
+            Self::IntentWalk => Some(Outcome::IntentionalWalk),
+            Self::HitByPitch => Some(Outcome::HitByPitch),
+            Self::CatcherInterference => Some(Outcome::CatcherInterference),
+            Self::Strikeout | Self::StrikeoutDoublePlay => Some(Outcome::Strikeout),
+            Self::SacFly | Self::SacFlyDoublePlay => Some(Outcome::SacFly),
+            Self::SacBunt | Self::SacBuntDoublePlay => Some(Outcome::SacBunt),
+            Self::GroundedIntoDoublePlay => Some(Outcome::GroundedIntoDoublePlay),
+            Self::FieldError => Some(Outcome::ReachedOnError),
+            Self::FieldersChoice | Self::FieldersChoiceOut => Some(Outcome::FieldersChoice),
+            Self::Groundout
+            | Self::BuntGroundout
+            | Self::Lineout
+            | Self::BuntLineout
+            | Self::Flyout
+            | Self::PopOut
+            | Self::BuntPopOut
+            | Self::Forceout
+            | Self::DoublePlay
+            | Self::TriplePlay
+            | Self::RunnerDoublePlay
+            | Self::RunnerTriplePlay
+            | Self::BatterOut => Some(Outcome::Out),
+            Self::Pickoff
+            | Self::PickoffError
+            | Self::CaughtStealing
+            | Self::PickoffCaughtStealing
+            | Self::WildPitch
+            | Self::RunnerOut
+            | Self::FieldOut
+            | Self::Balk
+            | Self::PassedBall
+            | Self::Error
+            | Self::StolenBase
+            | Self::GameAdvisory
+            | Self::Ejection => None,
+        }
+    }
+
+    /// Whether a play of this type is charged as an error to the team in the field.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::FieldError | Self::PickoffError | Self::Error | Self::CatcherInterference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::PlayField;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn play_types_with_a_batter_end_a_plate_appearance() {
+        for play_type in PlayType::iter() {
+            assert_eq!(play_type.outcome().is_some(), play_type.fields().contains(&PlayField::Batter), "{}", play_type.name());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use super::game::{PlayField, PlayType};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt, fs, path::Path};
+use strum::IntoEnumIterator;
+
+/// The positions that can be assigned to a player in the MLB profile.
+const MLB_POSITIONS: [&str; 18] = [
+    "PITCHER",
+    "CATCHER",
+    "FIRST_BASE",
+    "SECOND_BASE",
+    "THIRD_BASE",
+    "SHORTSTOP",
+    "LEFT_FIELD",
+    "CENTER_FIELD",
+    "RIGHT_FIELD",
+    "DESIGNATED_HITTER",
+    "PINCH_HITTER",
+    "PINCH_RUNNER",
+    "TWO_WAY_PLAYER",
+    "OUTFIELD",
+    "INFIELD",
+    "UTILITY",
+    "RELIEF_PITCHER",
+    "STARTING_PITCHER",
+];
+
+/// An error in a format profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileError {
+    pub message: String,
+}
+
+impl ProfileError {
+    fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// A play type declared by a format profile.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PlayKind {
+    /// The play type's name in the `type` field of a play introduction.
+    pub name: String,
+    /// The fields of the play's information line. A play type without any has no information line.
+    #[serde(default)]
+    pub information: Vec<PlayField>,
+    /// Whether the play has a movements line.
+    #[serde(default = "default_movements")]
+    pub movements: bool,
+}
+
+fn default_movements() -> bool {
+    true
+}
+
+impl PlayKind {
+    /// Whether the play has an information line.
+    pub fn has_information_line(&self) -> bool {
+        !self.information.is_empty()
+    }
+
+    /// The built-in play type with the same name and the same lines, whose plays are built as that type's variant of
+    /// `Play`. Plays of any other kind are built as `Play::Custom`.
+    pub fn play_type(&self) -> Option<PlayType> {
+        PlayType::iter().find(|play_type| play_type.name() == self.name && PlayKind::from(*play_type) == *self)
+    }
+}
+
+impl From<PlayType> for PlayKind {
+    fn from(play_type: PlayType) -> Self {
+        Self {
+            name: play_type.name().to_string(),
+            information: play_type.fields().iter().copied().filter(PlayField::is_information).collect(),
+            movements: play_type.has_movements_line(),
+        }
+    }
+}
+
+/// The play types and positions of a variant of the format, such as the one used by a league or data vendor.
+///
+/// A profile can be loaded from a TOML or JSON file, for example:
+///
+/// ```toml
+/// positions = ["PITCHER", "CATCHER", "FIELDER"]
+///
+/// [[play_types]]
+/// name = "Single"
+/// information = ["batter", "pitcher"]
+///
+/// [[play_types]]
+/// name = "Mercy Rule"
+/// movements = false
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FormatProfile {
+    /// The positions that can be assigned to a player in a team's roster.
+    pub positions: Vec<String>,
+    /// The play types, in the order they are listed in the play introduction regex.
+    pub play_types: Vec<PlayKind>,
+}
+
+impl FormatProfile {
+    /// The profile of the MLB data described in FORMAT.md, with every built-in play type.
+    pub fn mlb() -> Self {
+        Self {
+            positions: MLB_POSITIONS.iter().map(|position| position.to_string()).collect(),
+            play_types: PlayType::iter().map(PlayKind::from).collect(),
+        }
+    }
+
+    /// Reads a profile from a JSON document.
+    pub fn from_json(json: &str) -> Result<Self, ProfileError> {
+        serde_json::from_str::<Self>(json).map_err(|e| ProfileError::new(format!("invalid profile: {e}")))?.validated()
+    }
+
+    /// Reads a profile from a TOML document.
+    pub fn from_toml(toml: &str) -> Result<Self, ProfileError> {
+        toml::from_str::<Self>(toml).map_err(|e| ProfileError::new(format!("invalid profile: {e}")))?.validated()
+    }
+
+    /// Reads a profile from the file at `path`, as TOML if its extension is `.toml` and as JSON otherwise.
+    pub fn from_file(path: &Path) -> Result<Self, ProfileError> {
+        let text = fs::read_to_string(path).map_err(|e| ProfileError::new(format!("could not read {}: {e}", path.display())))?;
+        if path.extension().is_some_and(|extension| extension == "toml") {
+            Self::from_toml(&text)
+        } else {
+            Self::from_json(&text)
+        }
+    }
+
+    /// The play type with the given name.
+    pub fn play_kind(&self, name: &str) -> Option<&PlayKind> {
+        self.play_types.iter().find(|play_kind| play_kind.name == name)
+    }
+
+    /// Checks the profile, and puts the information fields of each play type in the order FORMAT.md lists them.
+    fn validated(mut self) -> Result<Self, ProfileError> {
+        if self.positions.is_empty() {
+            return Err(ProfileError::new("a profile needs at least one position"));
+        }
+        if self.play_types.is_empty() {
+            return Err(ProfileError::new("a profile needs at least one play type"));
+        }
+
+        let mut names = HashSet::new();
+        for play_kind in &mut self.play_types {
+            if !names.insert(play_kind.name.as_str()) {
+                return Err(ProfileError::new(format!("play type {:?} is declared more than once", play_kind.name)));
+            }
+            if play_kind.information.contains(&PlayField::Movements) {
+                return Err(ProfileError::new(format!("play type {:?} lists movements as information, use `movements = true` instead", play_kind.name)));
+            }
+            let mut fields = HashSet::new();
+            if let Some(field) = play_kind.information.iter().find(|field| !fields.insert(**field)) {
+                return Err(ProfileError::new(format!("play type {:?} lists {:?} more than once", play_kind.name, field.key())));
+            }
+            play_kind.information.sort_by_key(|field| PlayField::iter().position(|other| other == *field));
+        }
+
+        Ok(self)
+    }
+}
+
+impl Default for FormatProfile {
+    fn default() -> Self {
+        Self::mlb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mlb_profile_play_kinds_are_built_in() {
+        for play_kind in FormatProfile::mlb().play_types {
+            assert!(play_kind.play_type().is_some(), "{} should be built in", play_kind.name);
+        }
+    }
+
+    #[test]
+    fn profile_from_toml() {
+        let profile = FormatProfile::from_toml(r#"
+            positions = ["PITCHER", "FIELDER"]
+
+            [[play_types]]
+            name = "Single"
+            information = ["pitcher", "batter"]
+
+            [[play_types]]
+            name = "Balk"
+            information = ["pitcher", "catcher"]
+
+            [[play_types]]
+            name = "Mercy Rule"
+            movements = false
+        "#).unwrap();
+
+        assert_eq!(profile.play_types[0].information, vec![PlayField::Batter, PlayField::Pitcher]);
+        assert_eq!(profile.play_types[0].play_type(), Some(PlayType::Single));
+        assert_eq!(profile.play_types[1].play_type(), None);
+        assert!(!profile.play_types[2].has_information_line());
+        assert_eq!(profile, FormatProfile::from_json(&serde_json::to_string(&profile).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn invalid_profiles() {
+        let error = |json: &str| FormatProfile::from_json(json).unwrap_err().to_string();
+
+        assert_eq!(error(r#"{ "positions": [], "play_types": [{ "name": "Single" }] }"#), "a profile needs at least one position");
+        assert_eq!(
+            error(r#"{ "positions": ["PITCHER"], "play_types": [{ "name": "Single" }, { "name": "Single" }] }"#),
+            r#"play type "Single" is declared more than once"#,
+        );
+        assert_eq!(
+            error(r#"{ "positions": ["PITCHER"], "play_types": [{ "name": "Single", "information": ["batter", "batter"] }] }"#),
+            r#"play type "Single" lists "batter" more than once"#,
+        );
+        assert!(error(r#"{ "positions": ["PITCHER"], "play_types": [{ "name": "Single", "information": ["umpire"] }] }"#).starts_with("invalid profile: unknown variant `umpire`"));
+    }
+}
@@ -0,0 +1,527 @@
+//! The Python bindings, built with the `python` feature.
+
+use super::{
+    Base, BattingStats, BattingTotals, BoxScore, FormatProfile, Game, LiveFeedError, Movement, ParseError, Parser, Play, ProfileError, RetrosheetError, import_retrosheet_file,
+    parse_game_files,
+};
+use super::json_schema::{JsonType, KeyValueType, RegexDialect, RegexStyle, ToJsonSchema, ToRegex, Violation};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyTuple};
+use std::{collections::HashMap, fs, path::PathBuf};
+
+impl From<ParseError> for PyErr {
+    fn from(error: ParseError) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+impl From<ProfileError> for PyErr {
+    fn from(error: ProfileError) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+impl From<LiveFeedError> for PyErr {
+    fn from(error: LiveFeedError) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+impl From<RetrosheetError> for PyErr {
+    fn from(error: RetrosheetError) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+#[pymethods]
+impl Parser {
+    /// Creates a new parser. If `debug` is true, debug information will be printed during parsing. `profile` is the
+    /// path of a TOML or JSON format profile declaring the play types and positions, or `None` for the MLB format
+    /// described in `FORMAT.md`.
+    #[new]
+    #[pyo3(signature = (debug, profile = None))]
+    fn py_new(debug: bool, profile: Option<PathBuf>) -> PyResult<Self> {
+        Ok(match profile {
+            Some(path) => Self::with_profile(debug, FormatProfile::from_file(&path)?),
+            None => Self::new(debug),
+        })
+    }
+
+    /// Sets how strictly the generated regexes follow the canonical layout of a line. `style` is one of:
+    /// - `"canonical"`: the exact spacing and key order described in `FORMAT.md` (the default).
+    /// - `"flexible_whitespace"`: any amount of spaces or tabs between tokens, but keys in the canonical order.
+    /// - `"any_key_order"`: flexible whitespace, and the keys of objects with at most `max_members` members in any
+    ///   order.
+    #[pyo3(name = "set_regex_style", signature = (style, max_members = 3))]
+    fn py_set_regex_style(&mut self, style: &str, max_members: usize) -> PyResult<()> {
+        self.set_regex_style(RegexStyle::from_name(style, max_members).map_err(PyValueError::new_err)?);
+
+        Ok(())
+    }
+
+    /// Sets the regex engine that the generated regexes are written for. `dialect` is one of `"python_re"`,
+    /// `"rust_regex"` or `"interegular"`, or `None` for the portable syntax shared by all of them (the default).
+    #[pyo3(name = "set_regex_dialect", signature = (dialect = None))]
+    fn py_set_regex_dialect(&mut self, dialect: Option<&str>) -> PyResult<()> {
+        self.set_regex_dialect(dialect.map(RegexDialect::from_name).transpose().map_err(PyValueError::new_err)?);
+
+        Ok(())
+    }
+
+    /// Sets whether the generated regexes should also accept JSON-escaped forms of player names, such as `\u00e9` for
+    /// `é` or `\"` for `"`.
+    #[pyo3(name = "set_accept_json_escapes")]
+    fn py_set_accept_json_escapes(&mut self, accept_json_escapes: bool) {
+        self.set_accept_json_escapes(accept_json_escapes);
+    }
+
+    /// Sets whether player names should be canonicalized to their roster spelling, ignoring differences in accents,
+    /// case and punctuation. Every canonicalized name is recorded in `warnings`.
+    #[pyo3(name = "set_lenient")]
+    fn py_set_lenient(&mut self, lenient: bool) {
+        self.set_lenient(lenient);
+    }
+
+    /// Registers `alias` as another spelling of the roster name `canonical`, for use in lenient mode.
+    #[pyo3(name = "add_alias")]
+    fn py_add_alias(&mut self, alias: &str, canonical: &str) {
+        self.add_alias(alias, canonical);
+    }
+
+    /// The warnings raised while parsing so far.
+    #[getter(warnings)]
+    fn py_warnings(&self) -> Vec<String> {
+        self.warnings().to_vec()
+    }
+
+    /// Generates the regex for the next line to be parsed.
+    #[pyo3(name = "generate_regex")]
+    fn py_generate_regex(&mut self) -> String {
+        self.generate_regex()
+    }
+
+    /// Generates the JSON Schema document for the next line to be parsed, as a JSON string.
+    fn generate_json_schema(&self) -> String {
+        self.generate_schema().to_json_schema().to_string()
+    }
+
+    /// The schema for the next line to be parsed, as a tree of dicts and lists that `JsonType.from_tree` accepts.
+    fn generate_schema_tree(&self, py: Python<'_>) -> PyResult<PyObject> {
+        json_to_py(py, &self.generate_schema().to_tree())
+    }
+
+    /// Explains why the given line would be rejected as the next line, without updating the parser's state. Returns
+    /// every violation found, or an empty list if the line would be accepted.
+    #[pyo3(name = "explain")]
+    fn py_explain(&self, line: &str) -> Vec<Violation> {
+        self.explain(line)
+    }
+
+    /// Parses a line, updates the parser's state, and returns the next line's regex. If `want_regex` is false, the
+    /// regex is not generated and `None` is returned, which is faster when only the parsed game is needed.
+    #[pyo3(name = "parse_line", signature = (line, want_regex = true))]
+    fn py_parse_line(&mut self, line: &str, want_regex: bool) -> PyResult<Option<String>> {
+        self.advance(line)?;
+        Ok(want_regex.then(|| self.generate_regex()))
+    }
+
+    /// Parses every line of `text`, with the GIL released and without generating any regexes, and returns its games.
+    #[pyo3(name = "parse_str")]
+    fn py_parse_str(&mut self, py: Python<'_>, text: &str) -> PyResult<Vec<Game>> {
+        Ok(py.allow_threads(|| self.parse_str(text))?)
+    }
+
+    /// Parses every line of the file at `path`, with the GIL released and without generating any regexes, and returns
+    /// its games.
+    fn parse_file(&mut self, py: Python<'_>, path: PathBuf) -> PyResult<Vec<Game>> {
+        let text = py.allow_threads(|| fs::read_to_string(&path))?;
+        self.py_parse_str(py, &text)
+    }
+
+    /// Builds and returns the game.
+    #[pyo3(name = "finish")]
+    fn py_finish(&self) -> PyResult<Game> {
+        Ok(self.finish()?)
+    }
+
+    /// Returns the games completed so far, i.e. those followed by the context line of another game, and forgets them.
+    #[pyo3(name = "take_completed_games")]
+    fn py_take_completed_games(&mut self) -> Vec<Game> {
+        self.take_completed_games()
+    }
+
+    /// Forgets the game being parsed, the completed games and the warnings, so that the next line is parsed as the
+    /// context of a new game. The parser's settings and aliases are kept.
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[pymethods]
+impl Game {
+    /// Converts a game saved from the MLB Stats API's `feed/live` endpoint. `profile` is the path of a TOML or JSON
+    /// format profile declaring play types for the events the MLB format has none for, or `None` to reject them.
+    #[staticmethod]
+    #[pyo3(name = "from_live_feed", signature = (json, profile = None))]
+    fn py_from_live_feed(json: &str, profile: Option<PathBuf>) -> PyResult<Self> {
+        Ok(match profile {
+            Some(path) => Self::from_live_feed_with_profile(json, &FormatProfile::from_file(&path)?)?,
+            None => Self::from_live_feed(json)?,
+        })
+    }
+
+    /// Returns the game in the format described in FORMAT.md.
+    #[pyo3(name = "to_jsonl")]
+    fn py_to_jsonl(&self) -> String {
+        self.to_jsonl()
+    }
+
+    /// Returns the game's box score.
+    #[pyo3(name = "box_score")]
+    fn py_box_score(&self) -> BoxScore {
+        self.box_score()
+    }
+
+    /// Returns the game as a Retrosheet event file.
+    #[pyo3(name = "to_retrosheet")]
+    fn py_to_retrosheet(&self) -> PyResult<String> {
+        Ok(self.to_retrosheet()?)
+    }
+
+    /// Writes the game in the format described in FORMAT.md to the file at `path`.
+    fn write_file(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        Ok(py.allow_threads(|| fs::write(&path, self.to_jsonl()))?)
+    }
+}
+
+#[pymethods]
+impl Play {
+    /// The play type's name in the format, such as `"Home Run"`.
+    #[getter(play_type)]
+    fn py_play_type(&self) -> &str {
+        self.name()
+    }
+
+    /// The base the play happened at, or `None` if its type has none.
+    #[getter(base)]
+    fn py_base(&self) -> Option<Base> {
+        self.base()
+    }
+
+    /// The batter, or `None` if the play type has none.
+    #[getter(batter)]
+    fn py_batter(&self) -> Option<&str> {
+        self.batter()
+    }
+
+    /// The pitcher, or `None` if the play type has none.
+    #[getter(pitcher)]
+    fn py_pitcher(&self) -> Option<&str> {
+        self.pitcher()
+    }
+
+    /// The catcher, or `None` if the play type has none.
+    #[getter(catcher)]
+    fn py_catcher(&self) -> Option<&str> {
+        self.catcher()
+    }
+
+    /// The fielders, or `None` if the play type has none.
+    #[getter(fielders)]
+    fn py_fielders(&self) -> Option<Vec<String>> {
+        self.fielders().map(<[String]>::to_vec)
+    }
+
+    /// The runner, or `None` if the play type has none.
+    #[getter(runner)]
+    fn py_runner(&self) -> Option<&str> {
+        self.runner()
+    }
+
+    /// The scoring runner, or `None` if the play type has none.
+    #[getter(scoring_runner)]
+    fn py_scoring_runner(&self) -> Option<&str> {
+        self.scoring_runner()
+    }
+
+    /// The runners' movements, or `None` if the play type has no movements line.
+    #[getter(movements)]
+    fn py_movements(&self) -> Option<Vec<Movement>> {
+        self.movements().map(<[Movement]>::to_vec)
+    }
+}
+
+#[pymethods]
+impl Violation {
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl BoxScore {
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl BattingTotals {
+    /// The batting average, `H / AB`.
+    #[getter(average)]
+    fn py_average(&self) -> f64 {
+        self.average()
+    }
+
+    /// The on-base percentage, `(H + BB + HBP) / (AB + BB + HBP + SF)`.
+    #[getter(on_base_percentage)]
+    fn py_on_base_percentage(&self) -> f64 {
+        self.on_base_percentage()
+    }
+
+    /// The slugging percentage, `TB / AB`.
+    #[getter(slugging_percentage)]
+    fn py_slugging_percentage(&self) -> f64 {
+        self.slugging_percentage()
+    }
+
+    /// On-base plus slugging, `OBP + SLG`.
+    #[getter(on_base_plus_slugging)]
+    fn py_on_base_plus_slugging(&self) -> f64 {
+        self.on_base_plus_slugging()
+    }
+}
+
+#[pymethods]
+impl BattingStats {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    /// Accumulates the statistics of the games in the files at `paths` in parallel, with the GIL released. Returns the
+    /// statistics and a list of the errors of the files that could not be parsed.
+    #[staticmethod]
+    #[pyo3(name = "from_files", signature = (paths, threads = None))]
+    fn py_from_files(py: Python<'_>, paths: Vec<PathBuf>, threads: Option<usize>) -> PyResult<(Self, Vec<String>)> {
+        let (stats, errors) = py.allow_threads(|| Self::from_files(&paths, threads)).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((stats, errors.into_iter().map(|(path, e)| format!("{}: {e}", path.display())).collect()))
+    }
+
+    /// Adds the plate appearances and runs of every player in `game`.
+    #[pyo3(name = "add_game")]
+    fn py_add_game(&mut self, game: &Game) {
+        self.add_game(game);
+    }
+
+    /// Each player's totals, by name.
+    #[getter(players)]
+    fn py_players(&self) -> HashMap<String, BattingTotals> {
+        self.players().clone()
+    }
+
+    /// Each player's totals for each team they batted for, by `(team id, name)`.
+    #[getter(teams)]
+    fn py_teams(&self) -> HashMap<(u32, String), BattingTotals> {
+        self.teams().clone()
+    }
+
+    /// Each player's totals in each game, by `(game_pk, name)`.
+    #[getter(games)]
+    fn py_games(&self) -> HashMap<(u32, String), BattingTotals> {
+        self.games().clone()
+    }
+
+    /// The totals of all of a team's batters.
+    #[pyo3(name = "team_totals")]
+    fn py_team_totals(&self, team_id: u32) -> BattingTotals {
+        self.team_totals(team_id)
+    }
+}
+
+/// Parses the games in the files at `paths` in parallel, with the GIL released. Returns a list with, for each file, the
+/// list of its `Game`s or the `ValueError` explaining why it could not be parsed. Uses `threads` threads, or one per
+/// CPU.
+#[pyfunction]
+#[pyo3(signature = (paths, threads = None))]
+pub fn parse_games(py: Python<'_>, paths: Vec<PathBuf>, threads: Option<usize>) -> PyResult<Vec<PyObject>> {
+    let games = py.allow_threads(|| parse_game_files(&paths, threads)).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    games.into_iter().zip(&paths).map(|(game, path)| match game {
+        Ok(games) => Ok(games.into_pyobject(py)?.into_any().unbind()),
+        Err(e) => Ok(PyValueError::new_err(format!("{}: {e}", path.display())).into_value(py).into_any()),
+    }).collect()
+}
+
+/// Imports the games of the Retrosheet event file at `path`, with the GIL released.
+#[pyfunction]
+#[pyo3(name = "import_retrosheet")]
+pub fn py_import_retrosheet(py: Python<'_>, path: PathBuf) -> PyResult<Vec<Game>> {
+    Ok(py.allow_threads(|| import_retrosheet_file(&path))?)
+}
+
+/// Generates the regex for a JSON Schema document, given as a dict. See `JsonType::from_json_schema` for the supported
+/// subset of JSON Schema.
+#[pyfunction]
+pub fn schema_to_regex(schema: &Bound<'_, PyAny>) -> PyResult<String> {
+    match JsonType::from_json_schema(&py_to_json(schema)?) {
+        Ok(json_type) => Ok(json_type.to_regex()),
+        Err(e) => Err(PyValueError::new_err(e.to_string())),
+    }
+}
+
+/// Converts a Python value into JSON, through Python's `json` module.
+fn py_to_json(value: &Bound<'_, PyAny>) -> PyResult<serde_json::Value> {
+    let json = value.py().import("json")?.call_method1("dumps", (value,))?.extract::<String>()?;
+    serde_json::from_str(&json).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Converts JSON into the equivalent Python value of dicts, lists, strings, numbers, booleans and `None`.
+fn json_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
+    Ok(py.import("json")?.call_method1("loads", (value.to_string(),))?.unbind())
+}
+
+/// A JSON type, built from Python and rendered with the same emitters as the parser's regexes.
+#[derive(Clone)]
+#[pyclass(name = "JsonType", frozen)]
+pub struct PyJsonType {
+    json_type: JsonType,
+}
+
+impl From<JsonType> for PyJsonType {
+    fn from(json_type: JsonType) -> Self {
+        Self { json_type }
+    }
+}
+
+#[pymethods]
+impl PyJsonType {
+    #[staticmethod]
+    fn null() -> Self {
+        JsonType::null().into()
+    }
+
+    #[staticmethod]
+    fn boolean() -> Self {
+        JsonType::boolean().into()
+    }
+
+    /// An integer, at least `minimum` and at most `maximum` (inclusive) if they are given.
+    #[staticmethod]
+    #[pyo3(signature = (minimum = None, maximum = None))]
+    fn integer(minimum: Option<i64>, maximum: Option<i64>) -> PyResult<Self> {
+        match (minimum, maximum) {
+            (None, None) => Ok(JsonType::integer().into()),
+            (Some(minimum), None) => Ok(JsonType::integer_at_least(minimum).into()),
+            (None, Some(maximum)) => Ok(JsonType::integer_at_most(maximum).into()),
+            (Some(minimum), Some(maximum)) if minimum <= maximum => Ok(JsonType::integer_range(minimum, maximum).into()),
+            _ => Err(PyValueError::new_err("expected a minimum <= maximum")),
+        }
+    }
+
+    /// A decimal number between `minimum` and `maximum` (inclusive), with at most `max_fraction_digits` digits after
+    /// the decimal point.
+    #[staticmethod]
+    fn number(minimum: i64, maximum: i64, max_fraction_digits: usize) -> PyResult<Self> {
+        if minimum > maximum {
+            return Err(PyValueError::new_err("expected a minimum <= maximum"));
+        }
+        Ok(JsonType::number_range(minimum, maximum, max_fraction_digits).into())
+    }
+
+    /// A string whose contents match `pattern`, or any string without a `"` if no pattern is given.
+    #[staticmethod]
+    #[pyo3(signature = (pattern = None))]
+    fn string(pattern: Option<&str>) -> Self {
+        match pattern {
+            Some(pattern) => JsonType::string_with_regex(pattern).into(),
+            None => JsonType::string().into(),
+        }
+    }
+
+    /// A value that is one of the given options.
+    #[staticmethod]
+    fn enumeration(options: Vec<Bound<'_, PyAny>>) -> PyResult<Self> {
+        Ok(JsonType::enumeration(options.iter().map(py_to_json).collect::<PyResult<_>>()?).into())
+    }
+
+    /// An array of `items`, with between `min_items` and `max_items` (inclusive) items, which must all be different if
+    /// `unique`.
+    #[staticmethod]
+    #[pyo3(signature = (items, min_items = 0, max_items = None, unique = false))]
+    fn array(items: PyJsonType, min_items: usize, max_items: Option<usize>, unique: bool) -> PyResult<Self> {
+        if max_items.is_some_and(|max_items| min_items > max_items) {
+            return Err(PyValueError::new_err("min_items should not be more than max_items"));
+        }
+        Ok(JsonType::array_with_constraints(items.json_type, min_items, max_items, unique).into())
+    }
+
+    /// An object with the given members, in order. Each member is a `(key, type)` pair, or a `(key, type, required)`
+    /// triple for members that may be left out.
+    #[staticmethod]
+    fn object(members: Vec<Bound<'_, PyTuple>>) -> PyResult<Self> {
+        Ok(JsonType::object(members.iter().map(|member| {
+            let key = member.get_item(0)?.extract::<String>()?;
+            let value = member.get_item(1)?.extract::<PyJsonType>()?.json_type;
+            let required = if member.len() > 2 { member.get_item(2)?.extract::<bool>()? } else { true };
+
+            Ok(if required { KeyValueType::new(key, value) } else { KeyValueType::optional(key, value) })
+        }).collect::<PyResult<_>>()?).into())
+    }
+
+    /// A value of any of the given types.
+    #[staticmethod]
+    fn union(alternatives: Vec<PyJsonType>) -> Self {
+        JsonType::union(alternatives.into_iter().map(|alternative| alternative.json_type).collect()).into()
+    }
+
+    /// Builds a type from a tree of dicts and lists, in the form returned by `to_tree` and
+    /// `Parser.generate_schema_tree`.
+    #[staticmethod]
+    fn from_tree(tree: &Bound<'_, PyAny>) -> PyResult<Self> {
+        JsonType::from_tree(&py_to_json(tree)?).map(Self::from).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Imports a JSON Schema document, given as a dict.
+    #[staticmethod]
+    fn from_json_schema(schema: &Bound<'_, PyAny>) -> PyResult<Self> {
+        JsonType::from_json_schema(&py_to_json(schema)?).map(Self::from).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// The type as a tree of dicts and lists.
+    fn to_tree(&self, py: Python<'_>) -> PyResult<PyObject> {
+        json_to_py(py, &self.json_type.to_tree())
+    }
+
+    /// The equivalent JSON Schema document, as a dict.
+    fn to_json_schema(&self, py: Python<'_>) -> PyResult<PyObject> {
+        json_to_py(py, &self.json_type.to_json_schema())
+    }
+
+    /// Generates the regex for the type. See `Parser.set_regex_style` and `Parser.set_regex_dialect` for the styles and
+    /// dialects.
+    #[pyo3(signature = (style = "canonical", max_members = 3, dialect = None))]
+    fn to_regex(&self, style: &str, max_members: usize, dialect: Option<&str>) -> PyResult<String> {
+        let style = RegexStyle::from_name(style, max_members).map_err(PyValueError::new_err)?;
+        let dialect = dialect.map(RegexDialect::from_name).transpose().map_err(PyValueError::new_err)?;
+
+        Ok(self.json_type.to_regex_with(style, dialect))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JsonType({})", self.json_type.to_tree())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_with_one_bound() {
+        assert_eq!(PyJsonType::integer(Some(-3), None).unwrap().json_type, JsonType::integer_at_least(-3));
+        assert_eq!(PyJsonType::integer(None, Some(12)).unwrap().json_type, JsonType::integer_at_most(12));
+        assert_eq!(PyJsonType::integer(Some(1), Some(9)).unwrap().json_type, JsonType::integer_range(1, 9));
+        assert!(PyJsonType::integer(Some(9), Some(1)).is_err());
+    }
+}
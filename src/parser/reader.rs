@@ -0,0 +1,238 @@
+use super::{Context, Game, ParseError, Parser, Play};
+use std::io::{BufRead, Lines};
+
+/// The non-empty lines of one or more concatenated games, with the number of each line.
+struct GameLines<R> {
+    lines: Lines<R>,
+    /// The number of lines read so far.
+    line_number: usize,
+    /// A line that was read but is to be parsed again, as the context line of the next game.
+    pending: Option<String>,
+    /// Whether to skip lines until the next context line, after a game could not be parsed.
+    skipping: bool,
+}
+
+impl<R: BufRead> GameLines<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_number: 0,
+            pending: None,
+            skipping: false,
+        }
+    }
+
+    /// Reads the next non-empty line to be parsed.
+    fn next_line(&mut self) -> Option<Result<String, ParseError>> {
+        if let Some(line) = self.pending.take() {
+            return Some(Ok(line));
+        }
+
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(self.error(format!("could not read line: {e}")))),
+            };
+            self.line_number += 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+            if self.skipping {
+                if !is_context_line(&line) {
+                    continue;
+                }
+                self.skipping = false;
+            }
+
+            return Some(Ok(line));
+        }
+    }
+
+    /// An error about the last line read.
+    fn error(&self, message: String) -> ParseError {
+        ParseError {
+            line_number: Some(self.line_number + 1),
+            message,
+        }
+    }
+
+    /// Parses `line` with `parser`, and on failure skips the rest of the game. A context line that fails because the
+    /// game before it was cut short starts the next game, so it is kept to be parsed again rather than skipped.
+    fn advance(&mut self, parser: &mut Parser, line: &str) -> Result<(), ParseError> {
+        let game_started = parser.lines_parsed > 0;
+        parser.advance(line).map_err(|e| {
+            if game_started && is_context_line(line) {
+                self.pending = Some(line.to_string());
+            } else {
+                self.skipping = true;
+            }
+            ParseError { line_number: Some(self.line_number), ..e }
+        })
+    }
+}
+
+/// Whether `line` is a game's context line.
+fn is_context_line(line: &str) -> bool {
+    serde_json::from_str::<Context>(line).is_ok()
+}
+
+/// Reads the games in a stream of one or more concatenated games, yielding each game once it is complete.
+///
+/// If a game cannot be parsed, its error is yielded and the rest of the game is skipped, so that the following games
+/// can still be read.
+pub struct GameReader<R> {
+    lines: GameLines<R>,
+    parser: Parser,
+}
+
+impl<R: BufRead> GameReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: GameLines::new(reader),
+            parser: Parser::new(false),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for GameReader<R> {
+    type Item = Result<Game, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next_line() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => return Some(Err(e)),
+                None if self.parser.lines_parsed > 0 => {
+                    let game = self.parser.finish();
+                    self.parser.reset();
+                    return Some(game);
+                }
+                None => return None,
+            };
+
+            if self.parser.starts_new_game(&line) {
+                self.lines.pending = Some(line);
+                let game = self.parser.finish();
+                self.parser.reset();
+                return Some(game);
+            }
+
+            if let Err(e) = self.lines.advance(&mut self.parser, &line) {
+                self.parser.reset();
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// Reads the plays in a stream of one or more concatenated games, yielding each play as soon as its last line is
+/// parsed.
+///
+/// If a game cannot be parsed, its error is yielded and the rest of the game is skipped, so that the following games
+/// can still be read.
+pub struct PlayStream<R> {
+    lines: GameLines<R>,
+    parser: Parser,
+    /// The number of plays of the current game yielded so far.
+    plays_yielded: usize,
+}
+
+impl<R: BufRead> PlayStream<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: GameLines::new(reader),
+            parser: Parser::new(false),
+            plays_yielded: 0,
+        }
+    }
+
+    /// The context of the game whose plays are being read.
+    pub fn context(&self) -> Option<&Context> {
+        self.parser.game_builder.context.as_ref()
+    }
+}
+
+impl<R: BufRead> Iterator for PlayStream<R> {
+    type Item = Result<Play, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(play) = self.parser.game_builder.plays.get(self.plays_yielded) {
+                self.plays_yielded += 1;
+                return Some(Ok(play.clone()));
+            }
+
+            let line = match self.lines.next_line()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if self.parser.starts_new_game(&line) {
+                self.parser.reset();
+                self.plays_yielded = 0;
+            }
+
+            if let Err(e) = self.lines.advance(&mut self.parser, &line) {
+                self.parser.reset();
+                self.plays_yielded = 0;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn game_reader_reads_concatenated_games() {
+        let games = GameReader::new(format!("{GAME}\n{GAME}").as_bytes()).collect::<Vec<_>>();
+
+        assert_eq!(games.len(), 2);
+        for game in games {
+            assert_eq!(game.unwrap().plays.len(), 78);
+        }
+    }
+
+    #[test]
+    fn game_reader_skips_the_rest_of_a_broken_game() {
+        let mut lines = GAME.lines().collect::<Vec<_>>();
+        lines[2] = "{ \"batter\": ";
+        let text = format!("{}\n{GAME}", lines.join("\n"));
+
+        let games = GameReader::new(text.as_bytes()).collect::<Vec<_>>();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].as_ref().unwrap_err().line_number, Some(3));
+        assert_eq!(games[1].as_ref().unwrap().plays.len(), 78);
+    }
+
+    #[test]
+    fn game_cut_short_does_not_hide_the_next_game() {
+        let truncated = GAME.lines().take(6).collect::<Vec<_>>().join("\n");
+        let text = format!("{truncated}\n{GAME}");
+
+        let games = GameReader::new(text.as_bytes()).collect::<Vec<_>>();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].as_ref().unwrap_err().line_number, Some(7));
+        assert_eq!(games[1].as_ref().unwrap().plays.len(), 78);
+
+        let plays = PlayStream::new(text.as_bytes()).collect::<Vec<_>>();
+        assert_eq!(plays.len(), 1 + 1 + 78);
+        assert!(plays[1].is_err());
+        assert!(plays[2..].iter().all(Result::is_ok));
+    }
+
+    #[test]
+    fn play_stream_yields_each_play() {
+        let game = Parser::new(false).parse_str(GAME).unwrap().remove(0);
+        let mut stream = PlayStream::new(GAME.as_bytes());
+
+        assert_eq!(stream.next().unwrap().unwrap(), game.plays[0]);
+        assert_eq!(stream.context(), Some(&game.context));
+        assert_eq!(stream.count(), 77);
+    }
+}
@@ -0,0 +1,1190 @@
+use super::{Base, Context, Game, Inning, Movement, Play, PlayBuilder, PlayField, PlayType, Player, Team, Weather};
+use std::{collections::HashMap, fmt, fs, path::Path};
+use unicode_normalization::UnicodeNormalization;
+
+/// The position in the format of each Retrosheet fielding position, from 1 (pitcher) to 12 (pinch runner).
+const FIELDING_POSITIONS: [&str; 12] = [
+    "PITCHER",
+    "CATCHER",
+    "FIRST_BASE",
+    "SECOND_BASE",
+    "THIRD_BASE",
+    "SHORTSTOP",
+    "LEFT_FIELD",
+    "CENTER_FIELD",
+    "RIGHT_FIELD",
+    "DESIGNATED_HITTER",
+    "PINCH_HITTER",
+    "PINCH_RUNNER",
+];
+
+/// The MLB team id of each Retrosheet team code, including the codes of the franchises' earlier homes.
+const TEAM_IDS: [(&str, u32); 45] = [
+    ("ANA", 108), ("CAL", 108), ("LAA", 108),
+    ("ARI", 109),
+    ("ATL", 144), ("MLN", 144), ("BSN", 144),
+    ("BAL", 110), ("SLA", 110),
+    ("BOS", 111),
+    ("CHA", 145),
+    ("CHN", 112),
+    ("CIN", 113),
+    ("CLE", 114),
+    ("COL", 115),
+    ("DET", 116),
+    ("HOU", 117),
+    ("KCA", 118),
+    ("LAN", 119), ("BRO", 119),
+    ("MIA", 146), ("FLO", 146),
+    ("MIL", 158), ("SE1", 158),
+    ("MIN", 142), ("WS1", 142),
+    ("NYA", 147),
+    ("NYN", 121),
+    ("OAK", 133), ("ATH", 133), ("KC1", 133), ("PHA", 133),
+    ("PHI", 143),
+    ("PIT", 134),
+    ("SDN", 135),
+    ("SEA", 136),
+    ("SFN", 137), ("NY1", 137),
+    ("SLN", 138),
+    ("TBA", 139),
+    ("TEX", 140), ("WS2", 140),
+    ("TOR", 141),
+    ("WAS", 120), ("MON", 120),
+];
+
+/// The weather condition of each value of a game's `sky` info record.
+const SKY_CONDITIONS: [(&str, &str); 6] = [
+    ("cloudy", "Cloudy"),
+    ("dome", "Dome"),
+    ("night", "Clear"),
+    ("overcast", "Overcast"),
+    ("sunny", "Sunny"),
+    ("unknown", "Unknown"),
+];
+
+/// An error raised while importing a Retrosheet event file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetrosheetError {
+    /// The number of the offending record's line, counting from 1, if the error is about a single record.
+    pub line_number: Option<usize>,
+    pub message: String,
+}
+
+impl RetrosheetError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            line_number: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RetrosheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line_number {
+            Some(line_number) => write!(f, "line {line_number}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Splits a record into its comma-separated fields, removing the quotes around fields such as player names. A quote
+/// inside a quoted field is written twice.
+fn record_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.next_if_eq(&'"').is_some() => field.push('"'),
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+/// Splits `text` at each `separator` that is not between parentheses.
+fn split_outside_parentheses(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
+
+/// The contents of each parenthesized group in `text`, e.g. `["25", "UR"]` for `3XH(25)(UR)`.
+fn parenthesized(text: &str) -> Vec<&str> {
+    text.split('(').skip(1).filter_map(|group| group.split_once(')').map(|(group, _)| group)).collect()
+}
+
+/// The fielding positions named by the digits of `text`.
+fn fielder_numbers(text: &str) -> Vec<usize> {
+    text.chars().filter_map(|c| c.to_digit(10)).map(|digit| digit as usize).filter(|number| *number > 0).collect()
+}
+
+/// The base named by `c`: 0 for the batter, 1 to 3 for the bases, and 4 for home.
+fn base_number(c: char) -> Option<usize> {
+    match c {
+        'B' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '3' => Some(3),
+        'H' => Some(4),
+        _ => None,
+    }
+}
+
+fn base(number: usize) -> Base {
+    match number {
+        1 => Base::First,
+        2 => Base::Second,
+        3 => Base::Third,
+        _ => Base::Home,
+    }
+}
+
+/// A runner's advance from one base to another, or out on the way, as numbered by `base_number`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Advance {
+    from: usize,
+    to: usize,
+    out: bool,
+    /// The fielding positions of the fielders who put the runner out.
+    fielders: Vec<usize>,
+}
+
+impl Advance {
+    fn new(from: usize, to: usize, out: bool) -> Self {
+        Self { from, to, out, fielders: Vec::new() }
+    }
+
+    /// Parses an explicit advance such as `1-2`, `B-1` or `3XH(25)`. A runner thrown out on a play with an error, such
+    /// as `1X3(E5)`, is safe.
+    fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        let from = base_number(chars.next()?)?;
+        let out = match chars.next()? {
+            '-' => false,
+            'X' => true,
+            _ => return None,
+        };
+        let to = base_number(chars.next()?)?;
+        let groups = parenthesized(text);
+        let out = out && !groups.iter().any(|group| group.contains('E'));
+        let fielders = if out {
+            groups.iter().filter(|group| group.chars().all(|c| c.is_ascii_digit())).flat_map(|group| fielder_numbers(group)).collect()
+        } else {
+            Vec::new()
+        };
+
+        Some(Self { from, to, out, fielders })
+    }
+}
+
+/// A running event such as `SB2;SB3`, `CS2(24)`, `PO1(E3)` or `POCS2(1361)`: its play type, the base it is named
+/// after, the base of its runner, the advances it implies and the fielders who made it.
+struct RunningEvent {
+    play_type: PlayType,
+    base: usize,
+    runner: usize,
+    advances: Vec<Advance>,
+    fielders: Vec<usize>,
+}
+
+impl RunningEvent {
+    fn parse(piece: &str) -> Option<Self> {
+        if piece.starts_with("SB") {
+            let advances = piece.split(';').map(|steal| {
+                let to = base_number(steal.strip_prefix("SB")?.chars().next()?).filter(|to| *to > 0)?;
+                Some(Advance::new(to - 1, to, false))
+            }).collect::<Option<Vec<_>>>()?;
+            let first = advances.first()?;
+
+            return Some(Self { play_type: PlayType::StolenBase, base: first.to, runner: first.from, fielders: Vec::new(), advances });
+        }
+
+        let (play_type, rest) = if let Some(rest) = piece.strip_prefix("POCS") {
+            (PlayType::PickoffCaughtStealing, rest)
+        } else if let Some(rest) = piece.strip_prefix("PO") {
+            (PlayType::Pickoff, rest)
+        } else if let Some(rest) = piece.strip_prefix("CS") {
+            (PlayType::CaughtStealing, rest)
+        } else {
+            return None;
+        };
+        let base = base_number(rest.chars().next()?).filter(|base| *base > 0)?;
+        let runner = if play_type == PlayType::Pickoff { base } else { base - 1 };
+        let groups = parenthesized(rest);
+        let error = groups.iter().any(|group| group.contains('E'));
+        let play_type = if error && play_type == PlayType::Pickoff { PlayType::PickoffError } else { play_type };
+        let advances = if error { Vec::new() } else { vec![Advance::new(runner, base, true)] };
+
+        Some(Self { play_type, base, runner, advances, fielders: groups.iter().flat_map(|group| fielder_numbers(group)).collect() })
+    }
+}
+
+/// What a play record's event is.
+enum EventKind {
+    /// A play of the given type.
+    Play(PlayType),
+    /// Advances without a play type of their own, such as defensive indifference, which are added to the next play.
+    Advances,
+    /// No play, such as `NP` before a substitution or an error on a foul fly.
+    NoPlay,
+}
+
+/// A parsed play record event, such as `64(1)3/GDP` or `K+SB2.1-3`.
+struct Event {
+    kind: EventKind,
+    /// The advances of the batter and runners, whether explicit or implied by the event.
+    advances: Vec<Advance>,
+    /// The fielding positions of the fielders named by the event.
+    fielders: Vec<usize>,
+    /// The base a running event is named after.
+    base: Option<usize>,
+    /// The base of a running event's runner.
+    runner: Option<usize>,
+}
+
+impl Event {
+    fn parse(text: &str) -> Result<Self, String> {
+        let text = text.replace(['!', '#', '?'], "");
+        let (description, explicit) = text.split_once('.').unwrap_or((&text, ""));
+        let mut parts = split_outside_parentheses(description, '/').into_iter();
+        let basic = parts.next().unwrap_or_default();
+        let modifiers = parts.map(|modifier| modifier.chars().take_while(char::is_ascii_uppercase).collect::<String>()).collect::<Vec<_>>();
+        let has = |modifier: &str| modifiers.iter().any(|other| other == modifier);
+        let (main, secondary) = basic.split_once('+').map_or((basic, None), |(main, secondary)| (main, Some(secondary)));
+        let unknown = || format!("unknown event {text:?}");
+        let safe_on_error = explicit.split(';').any(|advance| parenthesized(advance).iter().any(|group| group.contains('E')));
+
+        let mut event = Event { kind: EventKind::NoPlay, advances: Vec::new(), fielders: Vec::new(), base: None, runner: None };
+        let mut batter = None;
+        if main == "NP" || main.starts_with("FLE") {
+            return Ok(event);
+        } else if main.starts_with("OA") && safe_on_error {
+            // other advances on which a runner is safe on an error, such as `OA.1-2(E?)`, are an error of their own
+            event.kind = EventKind::Play(PlayType::Error);
+        } else if main == "DI" || main.starts_with("OA") {
+            event.kind = EventKind::Advances;
+        } else if let Some(running_event) = RunningEvent::parse(main) {
+            event.kind = EventKind::Play(running_event.play_type);
+            event.advances = running_event.advances;
+            event.fielders = running_event.fielders;
+            event.base = Some(running_event.base);
+            event.runner = Some(running_event.runner);
+        } else if let Some(play_type) = [("WP", PlayType::WildPitch), ("PB", PlayType::PassedBall), ("BK", PlayType::Balk)]
+            .into_iter().find_map(|(code, play_type)| (main == code).then_some(play_type))
+        {
+            event.kind = EventKind::Play(play_type);
+        } else if main.starts_with('K') {
+            let double_play = ["DP", "TP"].iter().any(|modifier| has(modifier));
+            event.kind = EventKind::Play(if double_play { PlayType::StrikeoutDoublePlay } else { PlayType::Strikeout });
+            batter = Some(Advance::new(0, 0, true));
+        } else if let Some((play_type, to)) = match main {
+            "IW" | "I" => Some((PlayType::IntentWalk, 1)),
+            "HP" => Some((PlayType::HitByPitch, 1)),
+            "C" => Some((PlayType::CatcherInterference, 1)),
+            "DGR" => Some((PlayType::Double, 2)),
+            _ if main.starts_with('W') => Some((PlayType::Walk, 1)),
+            _ if main.starts_with('H') => Some((PlayType::HomeRun, 4)),
+            _ if main.starts_with("FC") => Some((PlayType::FieldersChoice, 1)),
+            _ if main.starts_with('E') => Some((PlayType::FieldError, 1)),
+            _ if main.starts_with('S') => Some((PlayType::Single, 1)),
+            _ if main.starts_with('D') => Some((PlayType::Double, 2)),
+            _ if main.starts_with('T') => Some((PlayType::Triple, 3)),
+            _ => None,
+        } {
+            event.kind = EventKind::Play(play_type);
+            event.fielders = if play_type == PlayType::CatcherInterference { vec![2] } else { fielder_numbers(main) };
+            batter = Some(Advance::new(0, to, false));
+        } else if main.starts_with(|c: char| c.is_ascii_digit()) {
+            let forced = parenthesized(main).iter().filter_map(|group| group.chars().next().and_then(base_number)).collect::<Vec<_>>();
+            let batter_out = forced.is_empty() || forced.contains(&0) || !main.ends_with(')');
+            event.fielders = fielder_numbers(&main.split('(').map(|part| part.split_once(')').map_or(part, |(_, rest)| rest)).collect::<String>());
+            event.advances = forced.iter().filter(|base| **base > 0).map(|base| Advance::new(*base, base + 1, true)).collect();
+            batter = Some(if batter_out { Advance::new(0, 0, true) } else { Advance::new(0, 1, false) });
+
+            let double_play = ["DP", "LDP", "FDP", "BGDP", "BPDP"].iter().any(|modifier| has(modifier));
+            let triple_play = ["TP", "GTP", "LTP", "FTP"].iter().any(|modifier| has(modifier));
+            let play_type = if has("GDP") {
+                PlayType::GroundedIntoDoublePlay
+            } else if has("SF") {
+                if double_play { PlayType::SacFlyDoublePlay } else { PlayType::SacFly }
+            } else if has("SH") {
+                if double_play { PlayType::SacBuntDoublePlay } else { PlayType::SacBunt }
+            } else if triple_play {
+                PlayType::TriplePlay
+            } else if double_play {
+                PlayType::DoublePlay
+            } else if has("FO") || !batter_out {
+                PlayType::Forceout
+            } else if let Some(play_type) = [
+                ("BG", PlayType::BuntGroundout),
+                ("BP", PlayType::BuntPopOut),
+                ("BL", PlayType::BuntLineout),
+                ("G", PlayType::Groundout),
+                ("L", PlayType::Lineout),
+                ("P", PlayType::PopOut),
+                ("F", PlayType::Flyout),
+            ].into_iter().find_map(|(modifier, play_type)| has(modifier).then_some(play_type)) {
+                play_type
+            } else if event.fielders.len() == 1 {
+                PlayType::Flyout
+            } else {
+                PlayType::Groundout
+            };
+            event.kind = EventKind::Play(play_type);
+        } else {
+            return Err(unknown());
+        }
+
+        if let Some(running_event) = secondary.and_then(RunningEvent::parse) {
+            event.advances.extend(running_event.advances);
+            event.fielders.extend(running_event.fielders);
+        }
+        event.advances.extend(batter);
+
+        // explicit advances replace the advances implied for the same runner
+        let explicit = explicit.split(';').filter(|advance| !advance.is_empty())
+            .map(|advance| Advance::parse(advance).ok_or_else(unknown))
+            .collect::<Result<Vec<_>, _>>()?;
+        event.advances.retain(|advance| !explicit.iter().any(|other| other.from == advance.from));
+        event.advances.extend(explicit);
+        // the lead runner moves first, and the batter last
+        event.advances.sort_by_key(|advance| if advance.from == 0 { 4 } else { 4 - advance.from });
+
+        Ok(event)
+    }
+}
+
+/// The `game_pk` of a Retrosheet game, which has none: a number from 1 to 999999 made from a hash (32-bit FNV-1a) of
+/// its id, so that it is the same each time the game is imported.
+fn game_pk(id: &str) -> u32 {
+    let hash = id.bytes().fold(0x811c9dc5_u32, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x01000193));
+    hash % 999_999 + 1
+}
+
+/// Imports the games of an event file one record at a time, keeping track of the lineups, the fielders and the
+/// runners on base.
+#[derive(Default)]
+struct GameImporter {
+    id: String,
+    info: HashMap<String, String>,
+    /// The players of the visiting and home teams, by id, in the order they enter the game.
+    rosters: [Vec<(String, Player)>; 2],
+    names: HashMap<String, String>,
+    /// The player in each batting order slot of the visiting and home teams.
+    lineups: [HashMap<usize, String>; 2],
+    /// The player at each fielding position of the visiting and home teams.
+    defenses: [HashMap<usize, String>; 2],
+    inning: Option<Inning>,
+    /// The id of the runner on each base.
+    bases: [Option<String>; 3],
+    /// The runners put on base by `radj` records, which come before the first play of their half inning.
+    runner_adjustments: Vec<(usize, String)>,
+    /// The movements of advances without a play type of their own, to be added to the next play.
+    pending_movements: Vec<Movement>,
+    plays: Vec<Play>,
+}
+
+impl GameImporter {
+    fn new(id: &str) -> Self {
+        Self { id: id.to_string(), ..Default::default() }
+    }
+
+    fn name(&self, id: &str) -> Result<String, String> {
+        self.names.get(id).cloned().ok_or_else(|| format!("unknown player {id:?}"))
+    }
+
+    /// The player at a fielding position of the team in the field.
+    fn fielder(&self, fielding_team: usize, position: usize) -> Result<String, String> {
+        let id = self.defenses[fielding_team].get(&position).ok_or_else(|| format!("no player at fielding position {position}"))?;
+        self.name(id)
+    }
+
+    /// Handles a `start` or `sub` record: `id,"name",team,batting order,fielding position`.
+    fn add_player(&mut self, fields: &[String]) -> Result<(), String> {
+        let [id, name, team, order, position] = fields else {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        };
+        let team = team.parse::<usize>().ok().filter(|team| *team < 2).ok_or_else(|| format!("invalid team {team:?}"))?;
+        let order = order.parse::<usize>().map_err(|_| format!("invalid batting order {order:?}"))?;
+        let position = position.parse::<usize>().ok().filter(|position| (1..=12).contains(position))
+            .ok_or_else(|| format!("invalid fielding position {position:?}"))?;
+
+        self.names.insert(id.clone(), name.clone());
+        if !self.rosters[team].iter().any(|(other, _)| other == id) {
+            self.rosters[team].push((id.clone(), Player { position: FIELDING_POSITIONS[position - 1].to_string(), name: name.clone() }));
+        }
+        let replaced = if order > 0 { self.lineups[team].insert(order, id.clone()) } else { None };
+        // a pinch runner takes the place of the runner they replace
+        if position == 12
+            && let Some(replaced) = replaced
+            && let Some(runner) = self.bases.iter_mut().flatten().find(|runner| **runner == replaced)
+        {
+            *runner = id.clone();
+        }
+        if position <= 9 {
+            self.defenses[team].insert(position, id.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Handles a `radj` record, which puts a runner on base, such as the runner who starts an extra inning on second.
+    fn adjust_runner(&mut self, fields: &[String]) -> Result<(), String> {
+        let [id, base] = fields else {
+            return Err(format!("expected 2 fields, found {}", fields.len()));
+        };
+        let base = base.chars().next().and_then(base_number).filter(|base| (1..=3).contains(base))
+            .ok_or_else(|| format!("invalid base {base:?}"))?;
+        self.runner_adjustments.push((base, id.clone()));
+
+        Ok(())
+    }
+
+    /// Handles a `play` record: `inning,team,batter,count,pitches,event`.
+    fn add_play(&mut self, fields: &[String]) -> Result<(), String> {
+        let [inning, team, batter_id, _, _, event] = fields else {
+            return Err(format!("expected 6 fields, found {}", fields.len()));
+        };
+        let inning = Inning {
+            number: inning.parse().map_err(|_| format!("invalid inning {inning:?}"))?,
+            top: team == "0",
+        };
+        if self.inning != Some(inning) {
+            self.inning = Some(inning);
+            self.bases = Default::default();
+            self.pending_movements.clear();
+        }
+        for (base, id) in self.runner_adjustments.drain(..) {
+            self.bases[base - 1] = Some(id);
+        }
+        let fielding_team = if inning.top { 1 } else { 0 };
+        let batter = self.name(batter_id)?;
+        let event = Event::parse(event)?;
+
+        let mut movements = Vec::new();
+        for advance in &event.advances {
+            let runner = if advance.from == 0 {
+                batter.clone()
+            } else {
+                let id = self.bases[advance.from - 1].as_ref().ok_or_else(|| format!("no runner on base {}", advance.from))?;
+                self.name(id)?
+            };
+            movements.push(Movement {
+                runner,
+                start_base: base(advance.from),
+                end_base: if advance.out { Base::Home } else { base(advance.to) },
+                is_out: advance.out,
+            });
+        }
+        let runner = event.runner.and_then(|base| self.bases[base - 1].as_ref()).map(|id| self.name(id)).transpose()?;
+
+        let runners = event.advances.iter().map(|advance| {
+            if advance.from == 0 { None } else { self.bases[advance.from - 1].clone() }
+        }).collect::<Vec<_>>();
+        for advance in event.advances.iter().filter(|advance| advance.from > 0) {
+            self.bases[advance.from - 1] = None;
+        }
+        for (advance, runner) in event.advances.iter().zip(runners) {
+            if !advance.out && (1..=3).contains(&advance.to) {
+                self.bases[advance.to - 1] = runner.or_else(|| Some(batter_id.clone()));
+            }
+        }
+
+        let play_type = match event.kind {
+            EventKind::Play(play_type) => play_type,
+            EventKind::Advances if movements.iter().any(|movement| movement.is_out) => PlayType::RunnerOut,
+            EventKind::Advances => {
+                self.pending_movements.extend(movements);
+                return Ok(());
+            }
+            EventKind::NoPlay => return Ok(()),
+        };
+        movements.splice(0..0, std::mem::take(&mut self.pending_movements));
+
+        let mut fielders = event.fielders.clone();
+        if fielders.is_empty() {
+            fielders = event.advances.iter().flat_map(|advance| advance.fielders.clone()).collect();
+        }
+        let runner = runner.or_else(|| {
+            let is_runner = |movement: &&Movement| movement.runner != batter && (play_type != PlayType::RunnerOut || movement.is_out);
+            movements.iter().find(is_runner).map(|movement| movement.runner.clone())
+        });
+
+        let mut play_builder = PlayBuilder::new();
+        play_builder.set_inning(inning);
+        play_builder.set_play_type(play_type);
+        for field in play_type.fields() {
+            match field {
+                PlayField::Base => play_builder.base = event.base.map(base),
+                PlayField::Batter => play_builder.set_batter(batter.clone()),
+                PlayField::Pitcher => play_builder.set_pitcher(self.fielder(fielding_team, 1)?),
+                PlayField::Catcher => play_builder.set_catcher(self.fielder(fielding_team, 2)?),
+                PlayField::Fielders if !fielders.is_empty() => {
+                    let fielders = fielders.iter().map(|position| self.fielder(fielding_team, *position)).collect::<Result<_, _>>()?;
+                    play_builder.set_fielders(fielders);
+                }
+                PlayField::Fielders => {}
+                PlayField::Runner => play_builder.runner = runner.clone(),
+                PlayField::ScoringRunner => {
+                    play_builder.scoring_runner = movements.iter()
+                        .find(|movement| movement.end_base == Base::Home && movement.start_base != Base::Home && !movement.is_out)
+                        .map(|movement| movement.runner.clone());
+                }
+                PlayField::Movements => play_builder.set_movements(movements.clone()),
+            }
+        }
+        self.plays.push(play_builder.build().map_err(|e| e.to_string())?);
+
+        Ok(())
+    }
+
+    /// The imported game, with a context made from its `info` records and rosters made from its players.
+    fn finish(self) -> Result<Game, String> {
+        let info = |key: &str| self.info.get(key).map(String::as_str);
+        let team_id = |key: &str| {
+            let code = info(key).ok_or_else(|| format!("missing {key} info"))?;
+            TEAM_IDS.iter().find(|(other, _)| *other == code).map(|(_, id)| *id).ok_or_else(|| format!("unknown team {code:?}"))
+        };
+        let [away_players, home_players] = self.rosters.map(|roster| roster.into_iter().map(|(_, player)| player).collect());
+
+        let context = Context {
+            game_pk: game_pk(&self.id),
+            date: info("date").ok_or("missing date info")?.replace('/', "-"),
+            venue_name: info("site").ok_or("missing site info")?.to_string(),
+            weather: Weather {
+                condition: info("sky").and_then(|sky| SKY_CONDITIONS.iter().find(|(other, _)| *other == sky)).map_or("Unknown", |(_, condition)| condition).to_string(),
+                temperature: info("temp").and_then(|temperature| temperature.parse().ok()).unwrap_or(0),
+                wind_speed: info("windspeed").and_then(|wind_speed| wind_speed.parse().ok()).unwrap_or(0),
+            },
+            home_team: Team { id: team_id("hometeam")?, players: home_players },
+            away_team: Team { id: team_id("visteam")?, players: away_players },
+        };
+
+        Ok(Game { context, plays: self.plays })
+    }
+}
+
+/// Imports the games of a Retrosheet event file (`.EVN` or `.EVA`) from its `id`, `info`, `start`, `sub`, `radj` and
+/// `play` records, naming fielders after the players at their positions.
+///
+/// Retrosheet games have no MLB `game_pk`, so each game's is made from its id, and may be shared by another game; set
+/// `context.game_pk` when the MLB one is known. A game's venue is its Retrosheet park id. As in the files the format
+/// was first written from, a runner who is out ends at home. Other advances (`OA`) on which a runner is put out are
+/// runner outs, and those on which a runner is safe on an error are errors. Advances without a play type of their own,
+/// such as defensive indifference (`DI`), are added to the movements of the next play.
+pub fn import_retrosheet(text: &str) -> Result<Vec<Game>, RetrosheetError> {
+    let mut games = Vec::new();
+    let mut game: Option<GameImporter> = None;
+    let finish = |game: GameImporter| {
+        let id = game.id.clone();
+        game.finish().map_err(|e| RetrosheetError::new(format!("game {id}: {e}")))
+    };
+
+    for (i, line) in text.lines().enumerate() {
+        let fields = record_fields(line);
+        let Some((record, fields)) = fields.split_first() else {
+            continue;
+        };
+        let result = match (record.as_str(), game.as_mut()) {
+            ("id", _) => {
+                games.extend(game.take().map(finish).transpose()?);
+                game = Some(GameImporter::new(fields.first().map_or("", String::as_str)));
+                Ok(())
+            }
+            ("info", Some(game)) => {
+                if let [key, value] = fields {
+                    game.info.insert(key.clone(), value.clone());
+                }
+                Ok(())
+            }
+            ("start" | "sub", Some(game)) => game.add_player(fields),
+            ("radj", Some(game)) => game.adjust_runner(fields),
+            ("play", Some(game)) => game.add_play(fields),
+            ("info" | "start" | "sub" | "radj" | "play", None) => Err("record before the first id record".to_string()),
+            _ => Ok(()),
+        };
+        result.map_err(|message| RetrosheetError {
+            line_number: Some(i + 1),
+            message: match &game {
+                Some(game) => format!("game {}: {message}", game.id),
+                None => message,
+            },
+        })?;
+    }
+    games.extend(game.map(finish).transpose()?);
+
+    Ok(games)
+}
+
+/// Imports the games of the Retrosheet event file at `path`, as `import_retrosheet` does.
+pub fn import_retrosheet_file(path: &Path) -> Result<Vec<Game>, RetrosheetError> {
+    let text = fs::read_to_string(path).map_err(|e| RetrosheetError::new(format!("could not read {}: {e}", path.display())))?;
+    import_retrosheet(&text)
+}
+
+/// The Retrosheet team code of an MLB team id, its current one if it has several, or the id itself if it has none.
+fn team_code(id: u32) -> String {
+    TEAM_IDS.iter().find(|(_, other)| *other == id).map_or_else(|| id.to_string(), |(code, _)| code.to_string())
+}
+
+/// The Retrosheet fielding position of a roster position, or `None` for a position such as `OUTFIELD` that does not
+/// name one.
+fn fielding_position(position: &str) -> Option<usize> {
+    match position {
+        "RELIEF_PITCHER" | "STARTING_PITCHER" => Some(1),
+        position => FIELDING_POSITIONS.iter().position(|other| *other == position).map(|i| i + 1),
+    }
+}
+
+/// The character naming base `number` in advances, as read by `base_number`.
+fn base_code(number: usize) -> char {
+    ['B', '1', '2', '3', 'H'][number.min(4)]
+}
+
+/// The number of `base` as the base a movement ends at, as numbered by `base_number`: home is 4.
+fn end_number(base: Base) -> usize {
+    match base {
+        Base::Home => 4,
+        Base::First => 1,
+        Base::Second => 2,
+        Base::Third => 3,
+    }
+}
+
+/// The advance of each runner over a play's movements, lead runner first and batter last. A runner who moves more
+/// than once, such as one who takes third on a wild pitch and then scores, advances from their first base to their
+/// last. A runner who is out ends at home, so they are put out at the next base.
+fn runner_advances(movements: &[Movement]) -> Vec<(&str, Advance)> {
+    let mut advances: Vec<(&str, Advance)> = Vec::new();
+    for movement in movements {
+        let from = if movement.start_base == Base::Home { 0 } else { end_number(movement.start_base) };
+        let to = if movement.is_out && movement.end_base == Base::Home { from + 1 } else { end_number(movement.end_base) };
+        match advances.iter_mut().find(|(runner, advance)| *runner == movement.runner && !advance.out && advance.to == from) {
+            Some((_, advance)) => {
+                advance.to = to;
+                advance.out = movement.is_out;
+            }
+            None => advances.push((&movement.runner, Advance::new(from, to, movement.is_out))),
+        }
+    }
+    advances.sort_by_key(|(_, advance)| if advance.from == 0 { 4 } else { 4 - advance.from });
+
+    advances
+}
+
+/// Writes an advance such as `1-3` or `2X3`, crediting an out to `fielders` and a runner who is safe to `error` if
+/// there are any.
+fn advance_code(advance: &Advance, fielders: &str, error: &str) -> String {
+    let to = if advance.out { advance.to.max(advance.from + 1) } else { advance.to };
+    let mut code = format!("{}{}{}", base_code(advance.from), if advance.out { 'X' } else { '-' }, base_code(to));
+    let credit = if advance.out { fielders } else { error };
+    if !credit.is_empty() {
+        code.push_str(&format!("({credit})"));
+    }
+
+    code
+}
+
+/// The event of a play record for a play of `play_type`, without its explicit advances, and the advances the event
+/// implies, or `None` for plays Retrosheet does not record, such as game advisories. `base` is the play's base as
+/// numbered by `base_number`, `fielders` the fielding positions of its fielders and `advances` its runners' advances.
+fn event_code(play_type: PlayType, base: usize, fielders: &[usize], advances: &[(&str, Advance)]) -> Option<(String, Vec<Advance>)> {
+    let numbers = fielders.iter().map(usize::to_string).collect::<String>();
+    let first = fielders.first().map_or_else(String::new, usize::to_string);
+    let base_name = base_code(base);
+    let batter_out = vec![Advance::new(0, 0, true)];
+    let out = |modifier: &str| (format!("{numbers}/{modifier}"), batter_out.clone());
+    let batter_to = |code: String, to: usize| (code, vec![Advance::new(0, to, false)]);
+
+    Some(match play_type {
+        PlayType::Groundout => out("G"),
+        PlayType::BuntGroundout => out("BG"),
+        PlayType::Lineout => out("L"),
+        PlayType::BuntLineout => out("BL"),
+        PlayType::Flyout => out("F"),
+        PlayType::PopOut => out("P"),
+        PlayType::BuntPopOut => out("BP"),
+        PlayType::DoublePlay | PlayType::RunnerDoublePlay => out("DP"),
+        PlayType::TriplePlay | PlayType::RunnerTriplePlay => out("TP"),
+        PlayType::GroundedIntoDoublePlay => out("GDP"),
+        PlayType::SacFly => out("SF"),
+        PlayType::SacFlyDoublePlay => out("SF/DP"),
+        PlayType::SacBunt => out("SH"),
+        PlayType::SacBuntDoublePlay => out("SH/DP"),
+        PlayType::Forceout => match advances.iter().find(|(_, advance)| advance.out && advance.from > 0) {
+            Some((_, forced)) => (
+                format!("{numbers}({})/FO", base_code(forced.from)),
+                vec![Advance::new(forced.from, forced.from + 1, true), Advance::new(0, 1, false)],
+            ),
+            None => out("FO"),
+        },
+        PlayType::Strikeout => ("K".to_string(), batter_out),
+        PlayType::StrikeoutDoublePlay => ("K/DP".to_string(), batter_out),
+        PlayType::BatterOut => ("2/BINT".to_string(), batter_out),
+        PlayType::Single => batter_to("S".to_string(), 1),
+        PlayType::Double => batter_to("D".to_string(), 2),
+        PlayType::Triple => batter_to("T".to_string(), 3),
+        PlayType::HomeRun => batter_to("HR".to_string(), 4),
+        PlayType::Walk => batter_to("W".to_string(), 1),
+        PlayType::IntentWalk => batter_to("IW".to_string(), 1),
+        PlayType::HitByPitch => batter_to("HP".to_string(), 1),
+        PlayType::CatcherInterference => batter_to("C/E2".to_string(), 1),
+        PlayType::FieldError => batter_to(format!("E{first}"), 1),
+        PlayType::FieldersChoice | PlayType::FieldersChoiceOut => batter_to(format!("FC{first}"), 1),
+        PlayType::StolenBase => (format!("SB{base_name}"), vec![Advance::new(base.saturating_sub(1), base, false)]),
+        PlayType::CaughtStealing => (format!("CS{base_name}({numbers})"), vec![Advance::new(base.saturating_sub(1), base, true)]),
+        PlayType::PickoffCaughtStealing => (format!("POCS{base_name}({numbers})"), vec![Advance::new(base.saturating_sub(1), base, true)]),
+        PlayType::Pickoff => (format!("PO{base_name}({numbers})"), vec![Advance::new(base, base, true)]),
+        PlayType::PickoffError => (format!("PO{base_name}(E{first})"), Vec::new()),
+        PlayType::WildPitch => ("WP".to_string(), Vec::new()),
+        PlayType::PassedBall => ("PB".to_string(), Vec::new()),
+        PlayType::Balk => ("BK".to_string(), Vec::new()),
+        PlayType::RunnerOut | PlayType::FieldOut | PlayType::Error => ("OA".to_string(), Vec::new()),
+        PlayType::GameAdvisory | PlayType::Ejection => return None,
+    })
+}
+
+/// Exports a game one play at a time, keeping track of the lineups, the fielders and the runners on base as
+/// `GameImporter` does, so that the players of each play are put in the game by `start`, `sub` and `radj` records
+/// before it.
+struct GameExporter<'a> {
+    game: &'a Game,
+    /// The Retrosheet-style id of each player, such as `lindf001` for Francisco Lindor.
+    ids: HashMap<String, String>,
+    records: Vec<String>,
+    /// The player in each batting order slot of the visiting and home teams.
+    lineups: [HashMap<usize, &'a str>; 2],
+    /// The player at each fielding position of the visiting and home teams.
+    defenses: [HashMap<usize, &'a str>; 2],
+    /// The number of plate appearances of the visiting and home teams, from which the slot of the next batter follows.
+    plate_appearances: [usize; 2],
+    inning: Option<Inning>,
+    /// The runner on each base.
+    bases: [Option<&'a str>; 3],
+}
+
+impl<'a> GameExporter<'a> {
+    fn new(game: &'a Game) -> Self {
+        Self {
+            game,
+            ids: HashMap::new(),
+            records: Vec::new(),
+            lineups: Default::default(),
+            defenses: Default::default(),
+            plate_appearances: [0; 2],
+            inning: None,
+            bases: Default::default(),
+        }
+    }
+
+    /// The id of a player: the first four letters of their last name, the first letter of their first name, and a
+    /// number telling apart players whose ids would otherwise be the same.
+    fn id(&mut self, name: &str) -> String {
+        if let Some(id) = self.ids.get(name) {
+            return id.clone();
+        }
+        let letters = |text: &str| text.nfkd().filter(char::is_ascii_alphabetic).map(|c| c.to_ascii_lowercase()).collect::<String>();
+        let (first, last) = name.split_once(' ').unwrap_or(("", name));
+        let prefix = format!("{:-<4.4}{}", letters(last), letters(first).chars().next().unwrap_or('-'));
+        let mut number = 1;
+        while self.ids.values().any(|id| *id == format!("{prefix}{number:03}")) {
+            number += 1;
+        }
+        let id = format!("{prefix}{number:03}");
+        self.ids.insert(name.to_string(), id.clone());
+
+        id
+    }
+
+    /// The fielding position of a player on the roster of the visiting (0) or home (1) team.
+    fn roster_position(&self, team: usize, name: &str) -> Option<usize> {
+        let team = if team == 0 { &self.game.context.away_team } else { &self.game.context.home_team };
+        team.players.iter().find(|player| player.name == name).and_then(|player| fielding_position(&player.position))
+    }
+
+    /// The batting order slot of a player, or 0 if they are not in the lineup.
+    fn slot(&self, team: usize, name: &str) -> usize {
+        self.lineups[team].iter().find(|(_, other)| **other == name).map_or(0, |(slot, _)| *slot)
+    }
+
+    /// Writes a `start` or `sub` record and puts the player in the lineup and in the field.
+    fn add_player(&mut self, record: &str, team: usize, name: &'a str, order: usize, position: usize) {
+        let id = self.id(name);
+        self.records.push(format!("{record},{id},\"{}\",{team},{order},{position}", name.replace('"', "\"\"")));
+        if order > 0 {
+            self.lineups[team].insert(order, name);
+        }
+        if position <= 9 {
+            self.defenses[team].retain(|_, other| *other != name);
+            self.defenses[team].insert(position, name);
+        }
+    }
+
+    /// Puts a player at a fielding position with a `sub` record, unless they are already there.
+    fn field(&mut self, team: usize, name: &'a str, position: usize) {
+        if self.defenses[team].get(&position) != Some(&name) {
+            self.add_player("sub", team, name, self.slot(team, name), position);
+        }
+    }
+
+    /// The fielding position of a fielder: the one they are playing, or else the one on their team's roster.
+    fn fielder_position(&self, team: usize, name: &str) -> Result<usize, String> {
+        if let Some((position, _)) = self.defenses[team].iter().find(|(_, other)| **other == name) {
+            return Ok(*position);
+        }
+        self.roster_position(team, name).filter(|position| *position <= 9).ok_or_else(|| format!("no fielding position for {name:?}"))
+    }
+
+    /// Writes the `id`, `version` and `info` records of the game.
+    fn add_info(&mut self) {
+        let context = &self.game.context;
+        let sky = SKY_CONDITIONS.iter().find(|(_, condition)| *condition == context.weather.condition).map_or("unknown", |(sky, _)| sky);
+        let site = if context.venue_name.contains(',') { format!("\"{}\"", context.venue_name) } else { context.venue_name.clone() };
+
+        self.records.push(format!("id,{}{}0", team_code(context.home_team.id), context.date.replace('-', "")));
+        self.records.push("version,2".to_string());
+        for (key, value) in [
+            ("visteam", team_code(context.away_team.id)),
+            ("hometeam", team_code(context.home_team.id)),
+            ("site", site),
+            ("date", context.date.replace('-', "/")),
+            ("number", "0".to_string()),
+            ("sky", sky.to_string()),
+            ("temp", context.weather.temperature.to_string()),
+            ("windspeed", context.weather.wind_speed.to_string()),
+        ] {
+            self.records.push(format!("info,{key},{value}"));
+        }
+    }
+
+    /// Writes the `start` records of each team: the batters of its first nine plate appearances, up to the first who
+    /// bats twice, at the positions on its roster, and its first pitcher, if they do not bat.
+    fn add_starters(&mut self) {
+        let plays = &self.game.plays;
+        for team in 0..2 {
+            let mut batters = Vec::new();
+            for batter in plays.iter().filter(|play| play.inning().top == (team == 0)).filter_map(Play::batter).take(9) {
+                if batters.contains(&batter) {
+                    break;
+                }
+                batters.push(batter);
+            }
+            let pitcher = plays.iter().filter(|play| play.inning().top != (team == 0)).find_map(Play::pitcher);
+            for (i, batter) in batters.into_iter().enumerate() {
+                // players without a fielding position of their own are taken to be designated hitters
+                let position = if Some(batter) == pitcher {
+                    1
+                } else {
+                    self.roster_position(team, batter).filter(|position| (2..=10).contains(position)).unwrap_or(10)
+                };
+                self.add_player("start", team, batter, i + 1, position);
+            }
+            if let Some(pitcher) = pitcher
+                && self.slot(team, pitcher) == 0
+            {
+                self.add_player("start", team, pitcher, 0, 1);
+            }
+        }
+    }
+
+    /// Writes the `play` record of the play at `index`, preceded by the `sub` and `radj` records that put its
+    /// players in the game.
+    fn add_play(&mut self, index: usize) -> Result<(), String> {
+        let plays = &self.game.plays;
+        let play = &plays[index];
+        let play_type = play.play_type().ok_or_else(|| format!("{:?} plays have no Retrosheet event", play.name()))?;
+        let inning = *play.inning();
+        if self.inning != Some(inning) {
+            self.inning = Some(inning);
+            self.bases = Default::default();
+        }
+        let batting_team = if inning.top { 0 } else { 1 };
+        let fielding_team = 1 - batting_team;
+
+        if let Some(pitcher) = play.pitcher() {
+            self.field(fielding_team, pitcher, 1);
+        }
+        if let Some(catcher) = play.catcher() {
+            self.field(fielding_team, catcher, 2);
+        }
+        let mut fielders = Vec::new();
+        for fielder in play.fielders().unwrap_or_default() {
+            let position = self.fielder_position(fielding_team, fielder)?;
+            self.field(fielding_team, fielder, position);
+            fielders.push(position);
+        }
+
+        // running plays belong to the batter at the plate: the next one to bat in the half inning, or else the one in
+        // the next slot, or else the last to bat
+        let batter = match play.batter() {
+            Some(batter) => {
+                let slot = self.plate_appearances[batting_team] % 9 + 1;
+                if self.lineups[batting_team].get(&slot) != Some(&batter) {
+                    self.add_player("sub", batting_team, batter, slot, 11);
+                }
+                self.plate_appearances[batting_team] += 1;
+                batter
+            }
+            None => plays[index + 1..].iter().take_while(|other| *other.inning() == inning).find_map(Play::batter)
+                .or_else(|| self.lineups[batting_team].get(&(self.plate_appearances[batting_team] % 9 + 1)).copied())
+                .or_else(|| plays[..index].iter().rev().filter(|other| other.inning().top == inning.top).find_map(Play::batter))
+                .ok_or("no batter at the plate")?,
+        };
+
+        let advances = runner_advances(play.movements().unwrap_or_default());
+        for (runner, advance) in advances.iter().filter(|(_, advance)| advance.from > 0) {
+            match self.bases[advance.from - 1] {
+                Some(occupant) if occupant == *runner => {}
+                Some(occupant) => self.add_player("sub", batting_team, runner, self.slot(batting_team, occupant), 12),
+                None => {
+                    let id = self.id(runner);
+                    self.records.push(format!("radj,{id},{}", advance.from));
+                }
+            }
+            self.bases[advance.from - 1] = Some(runner);
+        }
+
+        let base = play.base().map_or(0, end_number);
+        let Some((code, implied)) = event_code(play_type, base, &fielders, &advances) else {
+            return Ok(());
+        };
+        // advances are only written when the event does not imply them, and those of other advances name the fielders
+        // who made an out, or the error on which the runners were safe, by an unknown fielder
+        let credit = if code == "OA" { fielders.iter().map(usize::to_string).collect() } else { String::new() };
+        let error = if play_type == PlayType::Error { "E?" } else { "" };
+        let explicit = advances.iter()
+            .filter(|(_, advance)| !implied.iter().any(|other| {
+                other.from == advance.from && other.out == advance.out && (advance.out || other.to == advance.to)
+            }))
+            .map(|(_, advance)| advance_code(advance, &credit, error))
+            .collect::<Vec<_>>();
+        let event = if explicit.is_empty() { code } else { format!("{code}.{}", explicit.join(";")) };
+        let id = self.id(batter);
+        self.records.push(format!("play,{},{batting_team},{id},??,,{event}", inning.number));
+
+        for (_, advance) in advances.iter().filter(|(_, advance)| advance.from > 0) {
+            self.bases[advance.from - 1] = None;
+        }
+        for (runner, advance) in &advances {
+            if !advance.out && (1..=3).contains(&advance.to) {
+                self.bases[advance.to - 1] = Some(runner);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Game {
+    /// The game as a Retrosheet event file, with an `id`, `info` records made from its context, and a `play` record
+    /// for each play, so that it can be checked with the Retrosheet tools.
+    ///
+    /// Players get Retrosheet-style ids made from their names. Each batter's slot follows from their team's plate
+    /// appearances, and `start`, `sub` and `radj` records put the batters, fielders and runners of each play in the
+    /// game before it. Fielders are numbered by the position they are playing, or else by their roster position, so a
+    /// fielder whose roster position is not a single fielding position, such as `OUTFIELD`, is an error until they have
+    /// been seen at one. Runner outs, field outs and errors are other advances (`OA`), so field outs are imported back
+    /// as runner outs. An error play does not name the fielder who made the error, so its runners are safe on an error
+    /// by an unknown fielder (`E?`), and a quote in a player's name is written twice. Counts and pitches are unknown,
+    /// and game advisories and ejections have no play records.
+    pub fn to_retrosheet(&self) -> Result<String, RetrosheetError> {
+        let mut exporter = GameExporter::new(self);
+        exporter.add_info();
+        exporter.add_starters();
+        for index in 0..self.plays.len() {
+            exporter.add_play(index).map_err(|e| RetrosheetError::new(format!("play {}: {e}", index + 1)))?;
+        }
+
+        Ok(exporter.records.iter().map(|record| format!("{record}\n")).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    const EVENTS: &str = include_str!("../../test_data/retrosheet/2024TST.EVN");
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+    const IMPORTED: &str = include_str!("../../test_data/retrosheet/2024TST.jsonl");
+
+    #[test]
+    fn event_file_is_imported_to_the_format() {
+        let games = import_retrosheet(EVENTS).unwrap();
+        let written = games.iter().map(Game::to_jsonl).collect::<String>();
+
+        assert_eq!(written, IMPORTED);
+        for game in &games {
+            assert!((1..=999_999).contains(&game.context.game_pk));
+            assert_eq!(Parser::new(false).parse_str(&game.to_jsonl()).unwrap().remove(0).plays, game.plays);
+        }
+    }
+
+    #[test]
+    fn event_codes() {
+        let play_type = |text: &str| match Event::parse(text).unwrap().kind {
+            EventKind::Play(play_type) => Some(play_type),
+            _ => None,
+        };
+
+        assert_eq!(play_type("S8/L"), Some(PlayType::Single));
+        assert_eq!(play_type("K"), Some(PlayType::Strikeout));
+        assert_eq!(play_type("64(1)3/GDP"), Some(PlayType::GroundedIntoDoublePlay));
+        assert_eq!(play_type("54(1)/FO/G"), Some(PlayType::Forceout));
+        assert_eq!(play_type("8/F"), Some(PlayType::Flyout));
+        assert_eq!(play_type("SB2"), Some(PlayType::StolenBase));
+        assert_eq!(play_type("PO1(E3)"), Some(PlayType::PickoffError));
+        assert_eq!(play_type("WP"), Some(PlayType::WildPitch));
+        assert_eq!(play_type("OA.1-2(E2)"), Some(PlayType::Error));
+        assert_eq!(play_type("OA.2X3(25)"), None);
+        assert_eq!(play_type("DGR/L9"), Some(PlayType::Double));
+        assert_eq!(play_type("NP"), None);
+        assert!(Event::parse("ZZ").is_err());
+    }
+
+    #[test]
+    fn implied_and_explicit_advances() {
+        let advances = |text: &str| Event::parse(text).unwrap().advances;
+
+        assert_eq!(advances("64(1)3/GDP"), vec![Advance::new(1, 2, true), Advance::new(0, 0, true)]);
+        assert_eq!(advances("54(1)/FO/G"), vec![Advance::new(1, 2, true), Advance::new(0, 1, false)]);
+        assert_eq!(advances("K+SB2.1-3"), vec![Advance::new(1, 3, false), Advance::new(0, 0, true)]);
+        assert_eq!(advances("SB2;SB3"), vec![Advance::new(2, 3, false), Advance::new(1, 2, false)]);
+        assert_eq!(advances("S9/G.2-H;1X3(96)"), vec![
+            Advance::new(2, 4, false),
+            Advance { from: 1, to: 3, out: true, fielders: vec![9, 6] },
+            Advance::new(0, 1, false),
+        ]);
+        assert_eq!(advances("E6/G.1-3(E6)"), vec![Advance::new(1, 3, false), Advance::new(0, 1, false)]);
+    }
+
+    #[test]
+    fn runner_adjustments_put_runners_on_base() {
+        let events = EVENTS.replacen("play,1,1,adama001,00,X,S9/G", "radj,diazd001,2\nplay,1,1,adama001,00,X,S9/G.2-H", 1);
+        let games = import_retrosheet(&events).unwrap();
+
+        assert_eq!(games[1].plays.last().unwrap().movements().unwrap()[0], Movement {
+            runner: "Dan Diaz".to_string(),
+            start_base: Base::Second,
+            end_base: Base::Home,
+            is_out: false,
+        });
+    }
+
+    #[test]
+    fn errors_have_line_numbers() {
+        let events = EVENTS.replacen("play,1,0,diazd001,00,X,63/G", "play,1,0,diazd001,00,X,ZZ", 1);
+        let error = import_retrosheet(&events).unwrap_err();
+
+        assert_eq!(error.line_number, Some(EVENTS.lines().position(|line| line.ends_with("63/G")).unwrap() + 1));
+        assert_eq!(error.message, "game CIN202403010: unknown event \"ZZ\"");
+    }
+
+    #[test]
+    fn games_are_exported_as_event_records() {
+        let events = Parser::new(false).parse_str(GAME).unwrap().remove(0).to_retrosheet().unwrap();
+
+        for record in [
+            "id,CIN202402250",
+            "info,visteam,ANA",
+            "start,rosek001,\"Kenny Rosenberg\",0,0,1",
+            "play,2,1,mathm001,??,,16/G.1X2",
+            "play,4,0,fontc001,??,,5(1)/FO.2-3",
+            "sub,adamj001,\"Jordyn Adams\",0,1,11",
+            "play,5,1,mathm001,??,,64/GDP.2-3;1X2",
+            "play,6,0,martj001,??,,8/SF.3-H",
+        ] {
+            assert!(events.lines().any(|line| line == record), "missing {record:?}");
+        }
+    }
+
+    #[test]
+    fn exported_games_are_imported_back() {
+        let games = Parser::new(false).parse_str(GAME).unwrap().into_iter().chain(import_retrosheet(EVENTS).unwrap());
+        // a fielder's choice names a single fielder, even one who handled the ball twice
+        let summary = |play: &Play| {
+            let mut fielders = play.fielders().map(<[String]>::to_vec);
+            fielders.iter_mut().for_each(Vec::dedup);
+            (play.name().to_string(), play.batter().map(str::to_string), fielders)
+        };
+
+        for game in games {
+            let imported = import_retrosheet(&game.to_retrosheet().unwrap()).unwrap();
+
+            assert_eq!(imported[0].plays.iter().map(summary).collect::<Vec<_>>(), game.plays.iter().map(summary).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn errors_and_outs_on_other_advances_are_imported_back() {
+        let inning = r#"{ "inning": { "number": 1, "top": true }, "type": "#;
+        let plays = [
+            r#""Walk" }
+{ "batter": "Nelson Rada", "pitcher": "Hunter Greene" }
+{ "movements": [{ "runner": "Nelson Rada", "start_base": "home", "end_base": "1", "is_out": false }] }"#,
+            r#""Error" }
+{ "pitcher": "Hunter Greene", "catcher": "Luke Maile" }
+{ "movements": [{ "runner": "Nelson Rada", "start_base": "1", "end_base": "2", "is_out": false }] }"#,
+            r#""Field Out" }
+{ "fielders": ["Luke Maile", "Jonathan India"], "runner": "Nelson Rada" }
+{ "movements": [{ "runner": "Nelson Rada", "start_base": "2", "end_base": "home", "is_out": true }] }"#,
+            r#""Walk" }
+{ "batter": "Charles Leblanc", "pitcher": "Hunter Greene" }
+{ "movements": [{ "runner": "Charles Leblanc", "start_base": "home", "end_base": "1", "is_out": false }] }"#,
+            r#""Runner Out" }
+{ "fielders": ["Jonathan India"], "runner": "Charles Leblanc" }
+{ "movements": [{ "runner": "Charles Leblanc", "start_base": "1", "end_base": "home", "is_out": true }] }"#,
+            r#""Strikeout" }
+{ "batter": "Mickey Moniak", "pitcher": "Hunter Greene" }
+{ "movements": [{ "runner": "Mickey Moniak", "start_base": "home", "end_base": "home", "is_out": true }] }"#,
+        ];
+        let text = format!("{}\n{}\n", GAME.lines().next().unwrap(), plays.map(|play| format!("{inning}{play}")).join("\n"));
+        let game = Parser::new(false).parse_str(&text).unwrap().remove(0);
+        let events = game.to_retrosheet().unwrap();
+        let imported = import_retrosheet(&events).unwrap().remove(0);
+
+        assert!(events.contains(",OA.1-2(E?)\n"), "{events}");
+        assert!(events.contains(",OA.2X3(24)\n"), "{events}");
+        assert_eq!(imported.plays.iter().map(Play::name).collect::<Vec<_>>(), ["Walk", "Error", "Runner Out", "Walk", "Runner Out", "Strikeout"]);
+        assert_eq!(imported.plays[1], game.plays[1]);
+        for index in [2, 4] {
+            assert_eq!(
+                (imported.plays[index].fielders(), imported.plays[index].runner(), imported.plays[index].movements()),
+                (game.plays[index].fielders(), game.plays[index].runner(), game.plays[index].movements()),
+            );
+        }
+    }
+
+    #[test]
+    fn quotes_in_names_are_written_twice() {
+        let events = EVENTS.replace("\"Andy Adams\"", "\"Andy \"\"Double A\"\" Adams\"");
+        let game = import_retrosheet(&events).unwrap().remove(0);
+        let has_quoted_name = |game: &Game| game.context.away_team.players.iter().any(|player| player.name == "Andy \"Double A\" Adams");
+        assert!(has_quoted_name(&game));
+
+        let exported = game.to_retrosheet().unwrap();
+        assert!(exported.lines().any(|line| line.ends_with(",\"Andy \"\"Double A\"\" Adams\",0,1,8")), "{exported}");
+        assert!(has_quoted_name(&import_retrosheet(&exported).unwrap().remove(0)));
+    }
+}
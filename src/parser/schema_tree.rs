@@ -0,0 +1,156 @@
+use super::json_schema::{JsonType, KeyValueType, SchemaError, json_pointer_token};
+use serde_json::json;
+
+impl JsonType {
+    /// The type as a tree of JSON values, with each node tagged by its `kind`. Unlike the JSON Schema document, the
+    /// tree keeps the exact patterns used for the regex, and can be turned back into the same type with `from_tree`.
+    pub fn to_tree(&self) -> serde_json::Value {
+        match self {
+            JsonType::Null => json!({ "kind": "null" }),
+            JsonType::Boolean => json!({ "kind": "boolean" }),
+            JsonType::Integer { pattern, minimum, maximum } => json!({
+                "kind": "integer",
+                "pattern": pattern,
+                "minimum": minimum,
+                "maximum": maximum,
+            }),
+            JsonType::Number { pattern, minimum, maximum } => json!({
+                "kind": "number",
+                "pattern": pattern,
+                "minimum": minimum,
+                "maximum": maximum,
+            }),
+            JsonType::String { pattern } => json!({ "kind": "string", "pattern": pattern }),
+            JsonType::Enum(options) => json!({ "kind": "enum", "options": options }),
+            JsonType::Array { items, min_items, max_items, unique } => json!({
+                "kind": "array",
+                "items": items.to_tree(),
+                "min_items": min_items,
+                "max_items": max_items,
+                "unique": unique,
+            }),
+            JsonType::Object(members) => json!({
+                "kind": "object",
+                "members": members.iter().map(|member| json!({
+                    "key": member.key,
+                    "value": member.value.to_tree(),
+                    "required": member.required,
+                })).collect::<Vec<_>>(),
+            }),
+            JsonType::Union(alternatives) => json!({
+                "kind": "union",
+                "alternatives": alternatives.iter().map(JsonType::to_tree).collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    /// Builds a type from a tree in the form returned by `to_tree`.
+    ///
+    /// The `pattern` of an integer may be left out, in which case it is derived from its `minimum` and `maximum`, so
+    /// that changing the bounds of a tree does not require writing a new pattern.
+    pub fn from_tree(tree: &serde_json::Value) -> Result<Self, SchemaError> {
+        Self::from_tree_at("", tree)
+    }
+
+    fn from_tree_at(pointer: &str, tree: &serde_json::Value) -> Result<Self, SchemaError> {
+        let field_pointer = |field: &str| format!("{pointer}/{}", json_pointer_token(field));
+        let field = |field: &str| tree.get(field).filter(|value| !value.is_null());
+        let required_field = |name: &str| field(name).ok_or_else(|| SchemaError::new(pointer, format!("missing field {name:?}")));
+        let string_field = |name: &str| required_field(name)?.as_str().ok_or_else(|| {
+            SchemaError::new(&field_pointer(name), "expected a string")
+        });
+        let array_field = |name: &str| required_field(name)?.as_array().ok_or_else(|| {
+            SchemaError::new(&field_pointer(name), "expected an array")
+        });
+        let integer_field = |name: &str| field(name).map(|value| value.as_i64().ok_or_else(|| {
+            SchemaError::new(&field_pointer(name), format!("expected an integer, found {value}"))
+        })).transpose();
+
+        let json_type = match string_field("kind")? {
+            "null" => Self::null(),
+            "boolean" => Self::boolean(),
+            "integer" => match (field("pattern").and_then(serde_json::Value::as_str), integer_field("minimum")?, integer_field("maximum")?) {
+                (Some(pattern), minimum, maximum) => Self::Integer { pattern: pattern.to_string(), minimum, maximum },
+                (None, Some(minimum), Some(maximum)) if minimum <= maximum => Self::integer_range(minimum, maximum),
+                (None, None, None) => Self::integer(),
+                (None, _, _) => return Err(SchemaError::new(pointer, "an integer without a pattern needs a minimum <= maximum, or neither")),
+            },
+            "number" => Self::Number {
+                pattern: string_field("pattern")?.to_string(),
+                minimum: integer_field("minimum")?,
+                maximum: integer_field("maximum")?,
+            },
+            "string" => Self::string_with_regex(string_field("pattern")?),
+            "enum" => Self::enumeration(array_field("options")?.clone()),
+            "array" => {
+                let items = Self::from_tree_at(&field_pointer("items"), required_field("items")?)?;
+                let count_field = |name: &str| field(name).map(|value| value.as_u64().map(|value| value as usize).ok_or_else(|| {
+                    SchemaError::new(&field_pointer(name), format!("expected a non-negative integer, found {value}"))
+                })).transpose();
+                let min_items = count_field("min_items")?.unwrap_or(0);
+                let max_items = count_field("max_items")?;
+                if max_items.is_some_and(|max_items| min_items > max_items) {
+                    return Err(SchemaError::new(pointer, "min_items should not be more than max_items"));
+                }
+                let unique = field("unique").and_then(serde_json::Value::as_bool).unwrap_or(false);
+
+                Self::array_with_constraints(items, min_items, max_items, unique)
+            }
+            "object" => Self::object(array_field("members")?.iter().enumerate().map(|(i, member)| {
+                let member_pointer = format!("{}/{i}", field_pointer("members"));
+                let (Some(key), Some(value)) = (member.get("key").and_then(serde_json::Value::as_str), member.get("value")) else {
+                    return Err(SchemaError::new(&member_pointer, "expected a member with a string key and a value"));
+                };
+                let value = Self::from_tree_at(&format!("{member_pointer}/value"), value)?;
+
+                Ok(if member.get("required").and_then(serde_json::Value::as_bool).unwrap_or(true) {
+                    KeyValueType::new(key.to_string(), value)
+                } else {
+                    KeyValueType::optional(key.to_string(), value)
+                })
+            }).collect::<Result<_, _>>()?),
+            "union" => Self::union(array_field("alternatives")?.iter().enumerate().map(|(i, alternative)| {
+                Self::from_tree_at(&format!("{}/{i}", field_pointer("alternatives")), alternative)
+            }).collect::<Result<_, _>>()?),
+            kind => return Err(SchemaError::new(&field_pointer("kind"), format!("unknown kind {kind:?}"))),
+        };
+
+        Ok(json_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::json_schema::ToRegex;
+
+    fn play() -> JsonType {
+        JsonType::object(vec![
+            JsonType::key_value("type", JsonType::string_enum(["Single", "Double"])),
+            JsonType::key_value("inning", JsonType::integer_range(1, 20)),
+            JsonType::optional_key_value("fielders", JsonType::array_with_constraints(JsonType::string_with_regex("[A-Z][a-z]+"), 1, Some(4), false)),
+            JsonType::key_value("runner", JsonType::nullable(JsonType::string())),
+        ])
+    }
+
+    #[test]
+    fn json_type_round_trips_through_tree() {
+        assert_eq!(JsonType::from_tree(&play().to_tree()).unwrap(), play());
+    }
+
+    #[test]
+    fn tree_can_be_tweaked() {
+        let mut tree = play().to_tree();
+        tree["members"][1]["value"] = json!({ "kind": "integer", "minimum": 1, "maximum": 9 });
+        tree["members"].as_array_mut().unwrap().remove(2);
+
+        let regex = JsonType::from_tree(&tree).unwrap().to_regex();
+        let regex = regex::Regex::new(&format!("^({regex})$")).unwrap();
+        assert!(regex.is_match(r#"{ "type": "Single", "inning": 9, "runner": null }"#));
+        assert!(!regex.is_match(r#"{ "type": "Single", "inning": 10, "runner": null }"#));
+        assert!(!regex.is_match(r#"{ "type": "Single", "inning": 9, "fielders": ["Abc"], "runner": null }"#));
+
+        tree["members"][0]["value"]["kind"] = json!("tuple");
+        assert_eq!(JsonType::from_tree(&tree).unwrap_err().to_string(), r#"/members/0/value/kind: unknown kind "tuple""#);
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Game, Play, PlayField};
+use serde::Serialize;
+use serde_json::ser::Formatter;
+use std::io::{self, Write};
+use strum::IntoEnumIterator;
+
+/// Formats JSON with the spacing the generated regexes expect: `{ "key": value, ... }` for objects, `{}` for empty
+/// objects, and `[a, b]` for arrays.
+#[derive(Default)]
+struct LineFormatter {
+    /// Whether each object being written has had a member written yet.
+    objects: Vec<bool>,
+}
+
+impl Formatter for LineFormatter {
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.objects.push(false);
+        writer.write_all(b"{")
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.objects.pop().unwrap_or(false) {
+            writer.write_all(b" }")
+        } else {
+            writer.write_all(b"}")
+        }
+    }
+
+    fn begin_object_key<W: ?Sized + Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        if let Some(has_members) = self.objects.last_mut() {
+            *has_members = true;
+        }
+        writer.write_all(if first { b" " } else { b", " })
+    }
+
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+
+    fn begin_array_value<W: ?Sized + Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+}
+
+/// Writes `value` as a single line, followed by a line break.
+fn write_line<W: Write, T: Serialize + ?Sized>(writer: &mut W, value: &T) -> io::Result<()> {
+    let mut serializer = serde_json::Serializer::with_formatter(&mut *writer, LineFormatter::default());
+    value.serialize(&mut serializer).map_err(io::Error::from)?;
+    writer.write_all(b"\n")
+}
+
+/// Writes the introduction, information and movements lines of a play.
+fn write_play<W: Write>(writer: &mut W, play: &Play) -> io::Result<()> {
+    let serde_json::Value::Object(mut fields) = play.to_json() else {
+        unreachable!("a play is written as an object");
+    };
+    let introduction = ["inning", "type"].into_iter()
+        .filter_map(|key| fields.shift_remove(key).map(|value| (key.to_string(), value)))
+        .collect::<serde_json::Map<_, _>>();
+    let movements = fields.shift_remove("movements");
+
+    write_line(writer, &introduction)?;
+    if !fields.is_empty() {
+        write_line(writer, &fields)?;
+    }
+    if let Some(movements) = movements {
+        write_line(writer, &serde_json::json!({ "movements": movements }))?;
+    }
+
+    Ok(())
+}
+
+impl Play {
+    /// The play as a single JSON object with the fields of all of its lines: its inning and type, its information, and
+    /// its movements.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        object.insert("inning".to_string(), serde_json::json!(self.inning()));
+        object.insert("type".to_string(), serde_json::json!(self.name()));
+        for field in PlayField::iter() {
+            if let Some(value) = self.field_json(field) {
+                object.insert(field.key().to_string(), value);
+            }
+        }
+
+        serde_json::Value::Object(object)
+    }
+}
+
+impl Game {
+    /// Writes the game in the format described in FORMAT.md, one line per section, with the same spacing the
+    /// generated regexes expect. Parsing the written lines gives back the same game.
+    pub fn write_jsonl<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_line(&mut writer, &self.context)?;
+        for play in &self.plays {
+            write_play(&mut writer, play)?;
+        }
+
+        Ok(())
+    }
+
+    /// The game as a single JSON document, with its context and its plays as returned by `Play::to_json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "context": self.context,
+            "plays": self.plays.iter().map(Play::to_json).collect::<Vec<_>>(),
+        })
+    }
+
+    /// The game in the format described in FORMAT.md, as written by `write_jsonl`.
+    pub fn to_jsonl(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_jsonl(&mut buffer).expect("writing to a Vec should not fail");
+        String::from_utf8(buffer).expect("serde_json should write valid UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    const GAME: &str = include_str!("../../test_data/748236.jsonl");
+
+    #[test]
+    fn written_game_is_identical_to_parsed_game() {
+        let game = Parser::new(false).parse_str(GAME).unwrap().remove(0);
+        let written = game.to_jsonl();
+
+        assert_eq!(written, GAME);
+        assert_eq!(Parser::new(false).parse_str(&written).unwrap().remove(0).plays, game.plays);
+    }
+
+    #[test]
+    fn formatter_matches_regex_spacing() {
+        let mut buffer = Vec::new();
+        write_line(&mut buffer, &serde_json::json!({ "a": [1, { "b": [] }], "c": {}, "d": "Adolis García" })).unwrap();
+
+        assert_eq!(String::from_utf8(buffer).unwrap(), "{ \"a\": [1, { \"b\": [] }], \"c\": {}, \"d\": \"Adolis García\" }\n");
+    }
+}